@@ -80,6 +80,9 @@ impl Ellipsoid {
 
     /// Clarke 1866 ellipsoid (EPSG:7008).
     pub const CLARKE1866: Self = Self::new(6_378_206.4, 294.978_698_2);
+
+    /// Bessel 1841 ellipsoid (EPSG:7004).
+    pub const BESSEL1841: Self = Self::new(6_377_397.155, 299.152_812_8);
 }
 
 impl From<Geodesic> for Ellipsoid {
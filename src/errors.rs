@@ -26,6 +26,31 @@ pub enum ProjectionError {
     /// Returned when inverse projection of given values results in not finite results.
     #[error("Attempt to inverse project x: {0} y: {1} results in not finite result")]
     InverseProjectionImpossible(f64, f64),
+
+    /// Returned when given coordinates are outside of the domain in which the projection is defined.
+    #[error("Coordinates ({0}, {1}) are outside of the projection domain")]
+    OutOfDomain(f64, f64),
+
+    /// Returned when given point is on the far side of the horizon and therefore not visible
+    /// in the perspective projection.
+    #[error("Point lon: {0} lat: {1} is beyond the horizon and is not visible in the projection")]
+    PointNotVisible(f64, f64),
+
+    /// Returned when point given to inverse projection of a satellite view is outside of the Earth disk.
+    #[error("Point x: {0} y: {1} is outside of the Earth disk visible from the satellite")]
+    OffDisk(f64, f64),
+
+    /// Returned when iterative inverse projection does not converge to a point within the projection domain.
+    #[error("Inverse projection of x: {0} y: {1} did not converge to a valid solution")]
+    InverseNotConverged(f64, f64),
+
+    /// Returned when point falls into an interruption between lobes of an interrupted projection.
+    #[error("Point ({0}, {1}) is in the interruption between lobes of the projection")]
+    PointInInterruption(f64, f64),
+
+    /// Returned when cell identifier of a discrete global grid does not exist at given resolution.
+    #[error("Cell {0} does not exist at resolution {1} of the grid")]
+    InvalidCellId(u64, u8),
 }
 
 macro_rules! unpack_required_parameter {
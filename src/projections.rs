@@ -1,15 +1,91 @@
 //! Geographical projections implemented by the crate.
 
+pub mod aitoff;
+pub mod albers_equal_area_conic;
 pub mod azimuthal_equidistant;
+pub mod bonne;
+pub mod cassini_soldner;
+pub mod cubed_sphere;
+pub mod cylindrical_equal_area;
+pub mod equal_earth;
+pub mod equidistant_conic;
 pub mod equidistant_cylindrical;
+pub mod geostationary;
+pub mod gnomonic;
+pub mod goode_homolosine;
+pub mod hammer;
+pub mod healpix;
+pub mod hotine_oblique_mercator;
+pub mod interrupted;
+pub mod krovak;
+pub mod lambert_azimuthal_equal_area;
 pub mod lambert_conformal_conic;
 mod lon_lat;
+pub mod mercator;
+pub mod miller_cylindrical;
 pub mod modified_azimuthal_equidistant;
+pub mod mollweide;
+pub mod near_sided_perspective;
+mod numerical_inverse;
 pub mod oblique_lon_lat;
+pub mod orthographic;
+pub mod polar_stereographic;
+pub mod polyconic;
+pub mod rhealpix;
+pub mod robinson;
+pub mod sinusoidal;
+pub mod stereographic;
+pub mod transverse_mercator;
+pub mod two_point_equidistant;
+pub mod van_der_grinten;
+pub mod winkel_tripel;
 
+pub use aitoff::Aitoff;
+pub use albers_equal_area_conic::AlbersEqualAreaConic;
 pub use azimuthal_equidistant::AzimuthalEquidistant;
+pub use bonne::{Bonne, Werner};
+pub use cassini_soldner::CassiniSoldner;
+pub use cubed_sphere::CubedSphere;
+pub use cylindrical_equal_area::CylindricalEqualArea;
+pub use equal_earth::EqualEarth;
+pub use equidistant_conic::EquidistantConic;
 pub use equidistant_cylindrical::EquidistantCylindrical;
+pub use geostationary::Geostationary;
+pub use gnomonic::Gnomonic;
+pub use goode_homolosine::GoodeHomolosine;
+pub use hammer::Hammer;
+pub use healpix::Healpix;
+pub use hotine_oblique_mercator::HotineObliqueMercator;
+pub use interrupted::{Interrupted, Lobe};
+pub use krovak::Krovak;
+pub use lambert_azimuthal_equal_area::LambertAzimuthalEqualArea;
 pub use lambert_conformal_conic::LambertConformalConic;
 pub use lon_lat::LongitudeLatitude;
+pub use mercator::{Mercator, WebMercator};
+pub use miller_cylindrical::MillerCylindrical;
 pub use modified_azimuthal_equidistant::ModifiedAzimuthalEquidistant;
+pub use mollweide::Mollweide;
+pub use near_sided_perspective::NearSidedPerspective;
 pub use oblique_lon_lat::ObliqueLonLat;
+pub use orthographic::Orthographic;
+pub use polar_stereographic::PolarStereographic;
+pub use polyconic::Polyconic;
+pub use rhealpix::RHealpix;
+pub use robinson::Robinson;
+pub use sinusoidal::Sinusoidal;
+pub use stereographic::Stereographic;
+pub use transverse_mercator::TransverseMercator;
+pub use two_point_equidistant::TwoPointEquidistant;
+pub use van_der_grinten::VanDerGrinten;
+pub use winkel_tripel::WinkelTripel;
+
+/// Hemisphere of the Earth, used by projections which need to choose
+/// between northern and southern aspect or zone.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Hemisphere {
+    /// Northern hemisphere.
+    North,
+
+    /// Southern hemisphere.
+    South,
+}
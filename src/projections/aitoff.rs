@@ -0,0 +1,162 @@
+//! The Aitoff projection is a modified azimuthal map projection proposed by David A. Aitoff in 1889.
+//! Based on the equatorial form of the azimuthal equidistant projection, Aitoff first halves longitudes,
+//! then projects according to the azimuthal equidistant, and then stretches the result
+//! horizontally into a 2:1 ellipse to compensate for having halved the longitudes
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Aitoff_projection).
+//!
+//! The projection has no closed-form inverse, so the inverse is computed numerically
+//! and returns [`ProjectionError::InverseNotConverged`] from checked
+//! [`inverse_project`](Projection::inverse_project) when it does not converge.
+//!
+//! Summary by [Snyder (1993)](https://press.uchicago.edu/ucp/books/book/chicago/F/bo3632853.html):
+//!
+//! - Modified azimuthal.
+//! - Neither conformal nor equal-area.
+//! - Central meridian and Equator are straight lines, other meridians and parallels are curves.
+//! - Outline of the world map is an ellipse, with the Equator twice as long as the central meridian.
+//! - Used for world maps.
+//! - Presented by Aitoff in 1889.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::numerical_inverse;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`AitoffBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Aitoff {
+    lon_0: f64,
+    r: f64,
+}
+
+impl Aitoff {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> AitoffBuilder {
+        AitoffBuilder::default()
+    }
+
+    /// Computes the inverse projection numerically, returns `None` when it does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let x = x / self.r;
+        let y = y / self.r;
+        let (lambda, phi) = numerical_inverse::solve(unit_aitoff, x, y, (x, y))?;
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+}
+
+/// Builder struct which allows to construct [`Aitoff`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct AitoffBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for AitoffBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl AitoffBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Aitoff projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Aitoff, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(Aitoff {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for Aitoff {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the numerical inverse does not converge,
+    /// which also happens for points outside of the map.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let (x, y) = unit_aitoff(lambda, lat.to_radians());
+
+        (self.r * x, self.r * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
+
+/// Computes coordinates of the Aitoff projection on the unit sphere,
+/// formulas from Snyder (1993).
+pub(crate) fn unit_aitoff(lambda: f64, phi: f64) -> (f64, f64) {
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    let (sin_half_lambda, cos_half_lambda) = (lambda / 2.0).sin_cos();
+    let alpha = (cos_phi * cos_half_lambda).acos();
+
+    if approx_eq!(f64, alpha, 0.0) {
+        return (0.0, 0.0);
+    }
+
+    let sinc_alpha = alpha.sin() / alpha;
+
+    (
+        2.0 * cos_phi * sin_half_lambda / sinc_alpha,
+        sin_phi / sinc_alpha,
+    )
+}
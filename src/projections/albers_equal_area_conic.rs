@@ -0,0 +1,198 @@
+//! The Albers equal-area conic projection, or Albers projection, is a conic, equal area map projection
+//! that uses two standard parallels. Although scale and shape are not preserved, distortion is minimal
+//! between the standard parallels [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Albers_projection).
+//!
+//! Latitude in the inverse projection is computed iteratively with Newton's method,
+//! and the number of iterations is bounded.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Conic.
+//! - Equal-Area.
+//! - Parallels are unequally spaced arcs of concentric circles, more closely spaced at the north and south edges of the map.
+//! - Meridians are equally spaced radii of the same circles, cutting parallels at right angles.
+//! - There is no angular distortion in the direction of the standard parallels.
+//! - Poles are arcs of circles.
+//! - Used for equal-area maps of regions with predominant east-west expanse, especially the conterminous United States.
+//! - Presented by Albers in 1805.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::lambert_azimuthal_equal_area::{phi_from_q, q};
+use crate::projections::lambert_conformal_conic::{ensure_valid_parallels, m};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`AlbersEqualAreaConicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct AlbersEqualAreaConic {
+    lambda_0: f64,
+    n: f64,
+    c: f64,
+    rho_0: f64,
+    ellps: Ellipsoid,
+}
+
+impl AlbersEqualAreaConic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> AlbersEqualAreaConicBuilder {
+        AlbersEqualAreaConicBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`AlbersEqualAreaConic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct AlbersEqualAreaConicBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    std_parallel_1: Option<f64>,
+    std_parallel_2: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for AlbersEqualAreaConicBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            std_parallel_1: None,
+            std_parallel_2: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl AlbersEqualAreaConicBuilder {
+    /// *(required, alternative with [`standard_parallels`](AlbersEqualAreaConicBuilder::standard_parallels))* Sets first and second standard parallel (latitude) to the same value. Scale is true along that parallel.
+    pub const fn single_parallel(&mut self, standard_parallel: f64) -> &mut Self {
+        self.std_parallel_1 = Some(standard_parallel);
+        self.std_parallel_2 = Some(standard_parallel);
+        self
+    }
+
+    /// *(required, alternative with [`single_parallel`](AlbersEqualAreaConicBuilder::single_parallel))* Sets first and second standard parallel (latitude). Scale is true along those two standard parallels.
+    pub const fn standard_parallels(
+        &mut self,
+        std_parallel_1: f64,
+        std_parallel_2: f64,
+    ) -> &mut Self {
+        self.std_parallel_1 = Some(std_parallel_1);
+        self.std_parallel_2 = Some(std_parallel_2);
+        self
+    }
+
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// AEA projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - one or more longitudes are not within -180..180 range.
+    /// - one or more latitudes are not within -90..90 range.
+    /// - one or more arguments are not finite.
+    /// - absolute value of sum of standard parallels is not positive |`std_par_1` + `std_par_2`| == 0.
+    pub fn initialize_projection(&self) -> Result<AlbersEqualAreaConic, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let std_par_1 = unpack_required_parameter!(self, std_parallel_1);
+        let std_par_2 = unpack_required_parameter!(self, std_parallel_2);
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat, std_par_1, std_par_2);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+        ensure_within_range!(ref_lat, -90.0..90.0);
+        ensure_valid_parallels(std_par_1, std_par_2)?;
+
+        let phi_0 = ref_lat.to_radians();
+        let phi_1 = std_par_1.to_radians();
+        let phi_2 = std_par_2.to_radians();
+
+        let m_1 = m(phi_1, ellps);
+        let m_2 = m(phi_2, ellps);
+        let q_0 = q(phi_0, ellps);
+        let q_1 = q(phi_1, ellps);
+        let q_2 = q(phi_2, ellps);
+
+        // Formulas (14-12) to (14-15) from Snyder (1987)
+        let n = if approx_eq!(f64, std_par_1, std_par_2) {
+            phi_1.sin()
+        } else {
+            m_1.mul_add(m_1, -(m_2 * m_2)) / (q_2 - q_1)
+        };
+        let c = n.mul_add(q_1, m_1 * m_1);
+        let rho_0 = ellps.A * n.mul_add(-q_0, c).sqrt() / n;
+
+        Ok(AlbersEqualAreaConic {
+            lambda_0: ref_lon.to_radians(),
+            n,
+            c,
+            rho_0,
+            ellps,
+        })
+    }
+}
+
+impl Projection for AlbersEqualAreaConic {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lambda_0.to_degrees()).to_radians();
+
+        let q = q(phi, self.ellps);
+        let theta = self.n * lambda;
+        let rho = self.ellps.A * self.n.mul_add(-q, self.c).sqrt() / self.n;
+
+        let x = rho * theta.sin();
+        let y = rho.mul_add(-theta.cos(), self.rho_0);
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let sign = self.n.signum();
+        let rho = sign * x.hypot(self.rho_0 - y);
+        let theta = (x * sign).atan2((self.rho_0 - y) * sign);
+
+        let q =
+            (rho * self.n / self.ellps.A).mul_add(-(rho * self.n / self.ellps.A), self.c) / self.n;
+
+        let lambda = (theta / self.n) + self.lambda_0;
+        let phi = phi_from_q(q, self.ellps);
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
@@ -7,6 +7,8 @@
 //!
 //! This projection uses Geodesic computation (defined by [C. F. F. Karney (2013)](https://doi.org/10.1007/s00190-012-0578-z))
 //! to compute distances and azimuths between projected point and origin. So it might be slower than some other projections.
+//! When the eccentricity of the ellipsoid is zero, closed-form formulas for the sphere
+//! from Snyder (1987) are used instead, which are much faster.
 //!
 //! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
 //!
@@ -29,17 +31,28 @@
 use crate::{
     Ellipsoid, Projection, ProjectionError,
     errors::{ensure_finite, ensure_within_range, unpack_required_parameter},
+    projections::oblique_lon_lat::adjust_lon,
 };
+use float_cmp::approx_eq;
 use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+use std::f64::consts::PI;
 
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
+/// Tolerance of the sine of the angular distance from the centre
+/// for which the point is treated as the centre or its antipode.
+const TOLERANCE: f64 = 1e-12;
+
 /// Main projection struct that is constructed from [`AzimuthalEquidistantBuilder`] and used for computations.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub struct AzimuthalEquidistant {
     lon_0: f64,
     lat_0: f64,
+    sin_phi_0: f64,
+    cos_phi_0: f64,
+    r: f64,
+    spherical: bool,
     geod: Geodesic,
 }
 
@@ -52,6 +65,62 @@ impl AzimuthalEquidistant {
     pub fn builder() -> AzimuthalEquidistantBuilder {
         AzimuthalEquidistantBuilder::default()
     }
+
+    /// Computes the forward projection with formulas for the sphere from Snyder (1987).
+    fn spherical_project(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let delta_lambda = adjust_lon(lon - self.lon_0).to_radians();
+
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let (sin_dl, cos_dl) = delta_lambda.sin_cos();
+
+        let east = cos_phi * sin_dl;
+        let north = self.cos_phi_0 * sin_phi - self.sin_phi_0 * cos_phi * cos_dl;
+
+        // Angular distance c from the centre is computed from both its sine and cosine for accuracy
+        let sin_c = east.hypot(north);
+        let cos_c = self.sin_phi_0 * sin_phi + self.cos_phi_0 * cos_phi * cos_dl;
+        let c = sin_c.atan2(cos_c);
+
+        // The antipode of the centre is a circle on the map, so it cannot be projected
+        if sin_c < TOLERANCE {
+            return if cos_c > 0.0 {
+                (0.0, 0.0)
+            } else {
+                (f64::NAN, f64::NAN)
+            };
+        }
+
+        let k = self.r * c / sin_c;
+
+        (k * east, k * north)
+    }
+
+    /// Computes the inverse projection with formulas for the sphere from Snyder (1987).
+    fn spherical_inverse_project(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = x.hypot(y);
+
+        if approx_eq!(f64, rho, 0.0) {
+            return (self.lon_0, self.lat_0);
+        }
+
+        let c = rho / self.r;
+
+        // Points further than the antipode of the centre are outside of the map
+        if c > PI {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let (sin_c, cos_c) = c.sin_cos();
+
+        let phi = (cos_c * self.sin_phi_0 + y * sin_c * self.cos_phi_0 / rho).asin();
+        let lambda = (x * sin_c).atan2(rho * self.cos_phi_0 * cos_c - y * self.sin_phi_0 * sin_c);
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
 }
 
 /// Builder struct which allows to construct [`AzimuthalEquidistant`] projection.
@@ -113,9 +182,15 @@ impl AzimuthalEquidistantBuilder {
         ensure_within_range!(ref_lon, -180.0..180.0);
         ensure_within_range!(ref_lat, -90.0..90.0);
 
+        let (sin_phi_0, cos_phi_0) = ref_lat.to_radians().sin_cos();
+
         Ok(AzimuthalEquidistant {
             lon_0: ref_lon,
             lat_0: ref_lat,
+            sin_phi_0,
+            cos_phi_0,
+            r: ellps.A,
+            spherical: approx_eq!(f64, ellps.E, 0.0),
             geod: ellps.into(),
         })
     }
@@ -124,6 +199,10 @@ impl AzimuthalEquidistantBuilder {
 impl Projection for AzimuthalEquidistant {
     #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
     fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        if self.spherical {
+            return self.spherical_project(lon, lat);
+        }
+
         let (s12, azi1, _, _) = self.geod.inverse(self.lat_0, self.lon_0, lat, lon);
 
         let x = s12 * azi1.to_radians().sin();
@@ -134,6 +213,10 @@ impl Projection for AzimuthalEquidistant {
 
     #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
     fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        if self.spherical {
+            return self.spherical_inverse_project(x, y);
+        }
+
         let azi1 = x.atan2(y).to_degrees();
         let s12 = x.hypot(y);
 
@@ -0,0 +1,288 @@
+//! The Bonne projection is a pseudoconical equal-area map projection, in which parallels are
+//! concentric circular arcs and scale is true along the central meridian and all parallels.
+//! It was used for atlas maps of continents and for topographic maps of several European countries
+//! until the 20th century [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Bonne_projection).
+//!
+//! This module provides the [`Bonne`] projection with formulas for the ellipsoid
+//! from [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395), which reduce to the spherical
+//! formulas when the eccentricity is zero, and the [`Werner`] projection, which is the special case
+//! of Bonne projection with the standard parallel at the North Pole.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Pseudoconical.
+//! - Equal-area.
+//! - Parallels are concentric circular arcs, equally spaced.
+//! - Central meridian is a straight line; other meridians are complex curves.
+//! - Scale is true along the central meridian and all parallels.
+//! - No distortion along the central meridian and the standard parallel.
+//! - Used for atlas maps of continents and for topographic mapping of some countries.
+//! - Developed in its present form by Bonne in 1752; the Werner form was presented in 1514.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::equidistant_conic::{meridian_distance, phi_from_meridian_distance};
+use crate::projections::lambert_conformal_conic::m;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+use std::f64::consts::FRAC_PI_2;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Tolerance of the distance from the centre of parallels (scaled by the semi-major axis)
+/// and of the latitude for which the point is treated as the pole.
+const TOLERANCE: f64 = 1e-10;
+
+/// Main projection struct that is constructed from [`BonneBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Bonne {
+    lon_0: f64,
+    sign: f64,
+    rho_1: f64,
+    big_m_1: f64,
+    ellps: Ellipsoid,
+}
+
+impl Bonne {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> BonneBuilder {
+        BonneBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Bonne`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct BonneBuilder {
+    central_lon: f64,
+    std_parallel: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for BonneBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            std_parallel: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl BonneBuilder {
+    /// *(required)* Sets standard parallel (latitude). Scale is true without distortion along that parallel,
+    /// and point (0, 0) on the map is at its intersection with the central meridian.
+    pub const fn single_parallel(&mut self, standard_parallel: f64) -> &mut Self {
+        self.std_parallel = Some(standard_parallel);
+        self
+    }
+
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Bonne projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - standard parallel is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    /// - standard parallel is on the Equator.
+    pub fn initialize_projection(&self) -> Result<Bonne, ProjectionError> {
+        let central_lon = self.central_lon;
+        let std_par = unpack_required_parameter!(self, std_parallel);
+        let ellps = self.ellipsoid;
+        ensure_finite!(central_lon, std_par);
+
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&std_par) {
+            return Err(ProjectionError::ParamOutOfRange("std_par", -90.0, 90.0));
+        }
+
+        if approx_eq!(f64, std_par, 0.0) {
+            return Err(ProjectionError::IncorrectParams(
+                "standard parallel must not be on the Equator",
+            ));
+        }
+
+        let phi_1 = std_par.to_radians();
+
+        // Radius of the standard parallel, which is zero at the pole
+        let rho_1 = if approx_eq!(f64, std_par.abs(), 90.0) {
+            0.0
+        } else {
+            ellps.A * m(phi_1, ellps) / phi_1.sin()
+        };
+
+        Ok(Bonne {
+            lon_0: central_lon,
+            sign: std_par.signum(),
+            rho_1,
+            big_m_1: meridian_distance(phi_1, ellps),
+            ellps,
+        })
+    }
+}
+
+impl Projection for Bonne {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+
+        let rho = self.rho_1 + self.big_m_1 - meridian_distance(phi, self.ellps);
+
+        // The pole at the centre of parallels is a point
+        if rho.abs() <= TOLERANCE * self.ellps.A {
+            return (0.0, self.rho_1);
+        }
+
+        let big_e = self.ellps.A * m(phi, self.ellps) * lambda / rho;
+
+        let x = rho * big_e.sin();
+        let y = rho.mul_add(-big_e.cos(), self.rho_1);
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let dy = self.rho_1 - y;
+        let rho = self.sign * x.hypot(dy);
+
+        let phi = phi_from_meridian_distance(self.rho_1 + self.big_m_1 - rho, self.ellps);
+
+        // Longitude is undefined at the poles
+        let lambda = if phi.abs() < FRAC_PI_2 {
+            rho * (self.sign * x).atan2(self.sign * dy) / (self.ellps.A * m(phi, self.ellps))
+        } else if phi.abs() - TOLERANCE < FRAC_PI_2 {
+            0.0
+        } else {
+            return (f64::NAN, f64::NAN);
+        };
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Main projection struct that is constructed from [`WernerBuilder`] and used for computations.
+///
+/// Werner projection is the [`Bonne`] projection with the standard parallel at the North Pole,
+/// so the map has a heart-like (cordiform) shape and parallels are centred on the pole.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Werner {
+    bonne: Bonne,
+}
+
+impl Werner {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> WernerBuilder {
+        WernerBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Werner`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct WernerBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for WernerBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl WernerBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Werner projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Werner, ProjectionError> {
+        let bonne = Bonne::builder()
+            .central_lon(self.central_lon)
+            .single_parallel(90.0)
+            .ellipsoid(self.ellipsoid)
+            .initialize_projection()?;
+
+        Ok(Werner { bonne })
+    }
+}
+
+impl Projection for Werner {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.bonne.project_unchecked(lon, lat)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        self.bonne.inverse_project_unchecked(x, y)
+    }
+}
@@ -0,0 +1,289 @@
+//! The Cassini projection (also sometimes known as the Cassini–Soldner projection or Soldner projection)
+//! is a map projection first described in an approximate form by César-François Cassini de Thury in 1745.
+//! It is the transverse aspect of the equirectangular projection, in that the globe is first rotated
+//! so the central meridian becomes the "equator", and then the normal equirectangular projection
+//! is applied [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Cassini_projection).
+//!
+//! For the sphere, exact formulas from Snyder (1987) are used. For the ellipsoid, the series
+//! from Snyder (1987) are used, which are accurate only within a few degrees of the central meridian.
+//! The ellipsoidal inverse starts from the inverse series and is refined numerically, so that
+//! it is consistent with the forward projection. It returns [`ProjectionError::InverseNotConverged`]
+//! from checked [`inverse_project`](Projection::inverse_project) when it does not converge.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Cylindrical.
+//! - Central meridian, each meridian 90° from central meridian, and Equator are straight lines.
+//! - Other meridians and parallels are complex curves.
+//! - Neither equal-area nor conformal.
+//! - Scale is true along central meridian, and along lines perpendicular to central meridian.
+//! - Scale is constant but not true along lines parallel to central meridian on spherical form,
+//!   nearly so for ellipsoid.
+//! - Used primarily for large-scale mapping of areas predominantly north-south in extent.
+//! - Transverse of Equidistant Cylindrical projection.
+//! - Devised by C. F. Cassini de Thury in 1745 for the survey of France.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::equidistant_conic::{meridian_distance, phi_from_meridian_distance};
+use crate::projections::numerical_inverse;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+enum Form {
+    Spherical { phi_0: f64, r: f64 },
+    Ellipsoidal { m_0: f64, ellps: Ellipsoid },
+}
+
+/// Main projection struct that is constructed from [`CassiniSoldnerBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CassiniSoldner {
+    lon_0: f64,
+    false_easting: f64,
+    false_northing: f64,
+    form: Form,
+}
+
+impl CassiniSoldner {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> CassiniSoldnerBuilder {
+        CassiniSoldnerBuilder::default()
+    }
+
+    /// Computes the inverse projection, returns `None` when the ellipsoidal inverse does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let x = x - self.false_easting;
+        let y = y - self.false_northing;
+
+        let (lambda, phi) = match self.form {
+            Form::Spherical { phi_0, r } => {
+                // Spherical inverse formulas from Snyder (1987)
+                let big_d = y / r + phi_0;
+                let (sin_x, cos_x) = (x / r).sin_cos();
+
+                let phi = (big_d.sin() * cos_x).asin();
+                let lambda = (sin_x / cos_x).atan2(big_d.cos());
+
+                (lambda, phi)
+            }
+            Form::Ellipsoidal { m_0, ellps } => {
+                let x = x / ellps.A;
+                let y = (y + m_0) / ellps.A;
+
+                numerical_inverse::solve(
+                    |lambda, phi| {
+                        let (x, y) = ellipsoidal_series(lambda, phi, ellps);
+                        (x / ellps.A, y / ellps.A)
+                    },
+                    x,
+                    y,
+                    initial_guess(x, y, ellps),
+                )?
+            }
+        };
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+}
+
+/// Builder struct which allows to construct [`CassiniSoldner`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CassiniSoldnerBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    false_easting: f64,
+    false_northing: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for CassiniSoldnerBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            false_easting: 0.0,
+            false_northing: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl CassiniSoldnerBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets false easting and false northing (in meters) added to projected coordinates, defaults to `(0.0, 0.0)`.
+    pub const fn false_origin(&mut self, easting: f64, northing: f64) -> &mut Self {
+        self.false_easting = easting;
+        self.false_northing = northing;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// When the ellipsoid is a sphere, exact spherical formulas are used.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Cassini-Soldner projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - reference longitude is not within -180..180 range.
+    /// - reference latitude is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<CassiniSoldner, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let false_easting = self.false_easting;
+        let false_northing = self.false_northing;
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat, false_easting, false_northing);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&ref_lat) {
+            return Err(ProjectionError::ParamOutOfRange("ref_lat", -90.0, 90.0));
+        }
+
+        let phi_0 = ref_lat.to_radians();
+
+        let form = if approx_eq!(f64, ellps.E, 0.0) {
+            Form::Spherical { phi_0, r: ellps.A }
+        } else {
+            Form::Ellipsoidal {
+                m_0: meridian_distance(phi_0, ellps),
+                ellps,
+            }
+        };
+
+        Ok(CassiniSoldner {
+            lon_0: ref_lon,
+            false_easting,
+            false_northing,
+            form,
+        })
+    }
+}
+
+impl Projection for CassiniSoldner {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the ellipsoidal inverse does not converge.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+
+        let (x, y) = match self.form {
+            Form::Spherical { phi_0, r } => {
+                // Spherical forward formulas from Snyder (1987)
+                let x = (phi.cos() * lambda.sin()).asin();
+                let y = phi.tan().atan2(lambda.cos()) - phi_0;
+
+                (r * x, r * y)
+            }
+            Form::Ellipsoidal { m_0, ellps } => {
+                let (x, y) = ellipsoidal_series(lambda, phi, ellps);
+
+                (x, y - m_0)
+            }
+        };
+
+        (x + self.false_easting, y + self.false_northing)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
+
+/// Computes coordinates with the ellipsoidal series from Snyder (1987).
+/// Northing is measured from the Equator.
+fn ellipsoidal_series(lambda: f64, phi: f64, ellps: Ellipsoid) -> (f64, f64) {
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    let tan_phi = sin_phi / cos_phi;
+    let e2 = ellps.E.powi(2);
+
+    let nu = ellps.A / e2.mul_add(-sin_phi.powi(2), 1.0).sqrt();
+    let big_t = tan_phi.powi(2);
+    let big_a = lambda * cos_phi;
+    let big_a2 = big_a.powi(2);
+    let big_c = e2 * cos_phi.powi(2) / (1.0 - e2);
+
+    let x = nu
+        * big_a
+        * (big_t * big_a2).mul_add(
+            -(8.0 - big_t + 8.0 * big_c).mul_add(big_a2 / 120.0, 1.0 / 6.0),
+            1.0,
+        );
+    let y = (nu * tan_phi * big_a2).mul_add(
+        (5.0 - big_t + 6.0 * big_c).mul_add(big_a2 / 24.0, 0.5),
+        meridian_distance(phi, ellps),
+    );
+
+    (x, y)
+}
+
+/// Computes an approximation of longitude and latitude (in radians, relative to the central meridian)
+/// from coordinates scaled by the semi-major axis and measured from the Equator,
+/// with the inverse series from Snyder (1987).
+fn initial_guess(x: f64, y: f64, ellps: Ellipsoid) -> (f64, f64) {
+    let phi_1 = phi_from_meridian_distance(y * ellps.A, ellps);
+    let (sin_phi_1, cos_phi_1) = phi_1.sin_cos();
+    let tan_phi_1 = sin_phi_1 / cos_phi_1;
+    let e2 = ellps.E.powi(2);
+
+    let w = e2.mul_add(-sin_phi_1.powi(2), 1.0);
+    let nu_1 = 1.0 / w.sqrt();
+    let rho_1 = (1.0 - e2) / (w * w.sqrt());
+    let big_t_1 = tan_phi_1.powi(2);
+    let big_d = x / nu_1;
+    let big_d2 = big_d.powi(2);
+    let t_3 = 3.0f64.mul_add(big_t_1, 1.0);
+
+    let phi = (nu_1 * tan_phi_1 / rho_1)
+        .mul_add(-big_d2 * (t_3 * big_d2).mul_add(-1.0 / 24.0, 0.5), phi_1);
+    let lambda = big_d
+        * (big_t_1 * big_d2).mul_add((t_3 * big_d2).mul_add(1.0 / 15.0, -1.0 / 3.0), 1.0)
+        / cos_phi_1;
+
+    (lambda, phi)
+}
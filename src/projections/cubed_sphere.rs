@@ -0,0 +1,394 @@
+//! The cubed sphere is a gnomonic projection of the sphere onto six faces of the circumscribed cube.
+//! It is used as the horizontal grid of global atmospheric models, such as the FV3 dynamical core
+//! of NOAA's GFS, because it avoids the convergence of meridians at the poles
+//! [(Putman and Lin, 2007)](https://doi.org/10.1016/j.jcp.2007.07.022).
+//!
+//! Each face is projected from the centre of the sphere onto the plane tangent at the face centre.
+//! In the equidistant variant coordinates on the face are proportional to the distance in that plane,
+//! while in the equiangular variant they are proportional to the angle at the centre of the sphere,
+//! which gives grid cells of more uniform size. Note that FV3 grids generated with `gnomonic_ed` option
+//! are spaced equally along the edges of the faces and match neither of the variants exactly.
+//!
+//! [`CubedSphere::project_to_face`] returns the face and coordinates on that face, with axes
+//! oriented according to [`FaceOrientation`]. Functions of the [`Projection`] trait use the net
+//! of the cube, with equatorial faces placed side by side along the x axis and polar faces
+//! above and below the first face. Centres of grid cells can be computed with [`CubedSphere::cell_center`].
+//!
+//! As the projection is only defined for sphere, the semi-major axis is used as its radius.
+
+use crate::{
+    Ellipsoid, Projection, ProjectionError,
+    errors::{ensure_finite, ensure_within_range},
+    projections::oblique_lon_lat::adjust_lon,
+};
+use std::f64::consts::FRAC_PI_4;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Relative tolerance of the face coordinates for which point is still considered to be on the face.
+const EPS: f64 = 1e-12;
+
+/// Unit vectors of the face centre and of its x and y axes.
+type Face = [[f64; 3]; 3];
+
+/// Faces with axes of equatorial faces pointing east and north,
+/// and polar faces oriented as in the net of the cube.
+const ALIGNED_FACES: [Face; 6] = [
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]],
+    [[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, 0.0, -1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]],
+];
+
+/// Faces oriented as tiles of the FV3 grid mosaic.
+const FV3_FACES: [Face; 6] = [
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, 0.0, 1.0], [-1.0, 0.0, 0.0], [0.0, -1.0, 0.0]],
+    [[-1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, -1.0, 0.0]],
+    [[0.0, -1.0, 0.0], [0.0, 0.0, -1.0], [1.0, 0.0, 0.0]],
+    [[0.0, 0.0, -1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]],
+];
+
+/// Positions of faces in the net of the cube, in units of the face width.
+const NET_POSITIONS: [(i32, i32); 6] = [(0, 0), (1, 0), (0, 1), (2, 0), (-1, 0), (0, -1)];
+
+/// Variant of the projection, which determines how coordinates on the face
+/// are related to the gnomonic projection of that face.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum CubedSphereVariant {
+    /// Coordinates are proportional to the central angle between the face centre
+    /// and the point along each axis, so the grid lines are equally spaced in angle.
+    #[default]
+    Equiangular,
+
+    /// Coordinates are proportional to the distance from the face centre
+    /// in the tangent plane, so the grid lines are equally spaced in that plane.
+    Equidistant,
+}
+
+/// Orientation of axes on faces of the cube returned by [`CubedSphere::project_to_face`].
+/// In both orientations faces 0, 1, 3 and 4 are centred on the Equator at the central meridian
+/// and 90°, 180° and 270° eastwards of it, face 2 is centred on the North Pole
+/// and face 5 on the South Pole.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum FaceOrientation {
+    /// The x axis points east and the y axis north on all equatorial faces. Polar faces
+    /// are oriented as in the net of the cube unfolded from face 0, so their x axes point
+    /// towards face 1, the y axis of the north face points towards face 3 and the y axis
+    /// of the south face towards face 0.
+    #[default]
+    Aligned,
+
+    /// Faces are oriented as tiles 1 to 6 of the FV3 grid mosaic. The x axis points east
+    /// and the y axis north on faces 0 and 1, while on faces 3 and 4 the x axis points south
+    /// and the y axis east. On the north face x and y axes point towards faces 3 and 4,
+    /// and on the south face towards faces 1 and 0.
+    Fv3,
+}
+
+/// Main projection struct that is constructed from [`CubedSphereBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CubedSphere {
+    lon_0: f64,
+    r: f64,
+    half_width: f64,
+    variant: CubedSphereVariant,
+    orientation: FaceOrientation,
+}
+
+impl CubedSphere {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> CubedSphereBuilder {
+        CubedSphereBuilder::default()
+    }
+
+    /// Projects geographical coordinates (in degrees) onto the face of the cube
+    /// and returns the face number with coordinates (in meters) relative to the face centre.
+    /// Coordinates on each face are within `-w/2..=w/2` range, where `w` is the face width,
+    /// and their axes are oriented according to [`FaceOrientation`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::ProjectionImpossible`] when the result of projection is not finite.
+    pub fn project_to_face(&self, lon: f64, lat: f64) -> Result<(u8, f64, f64), ProjectionError> {
+        let (face, x, y) = self.locate_on_face(self.faces(), lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            return Err(ProjectionError::ProjectionImpossible(lon, lat));
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok((face as u8, x, y))
+    }
+
+    /// Inversely projects coordinates (in meters) relative to the centre of given face
+    /// to geographical coordinates (in degrees). This is the inverse of [`CubedSphere::project_to_face`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - face is not within 0..6 range.
+    /// - point is outside of the face.
+    /// - one or more arguments are not finite.
+    pub fn inverse_project_from_face(
+        &self,
+        face: u8,
+        x: f64,
+        y: f64,
+    ) -> Result<(f64, f64), ProjectionError> {
+        ensure_valid_face(face)?;
+        ensure_finite!(x, y);
+
+        if x.abs().max(y.abs()) > self.half_width * (1.0 + EPS) {
+            return Err(ProjectionError::OutOfDomain(x, y));
+        }
+
+        Ok(self.face_to_geographic(self.faces(), usize::from(face), x, y))
+    }
+
+    /// Returns geographical coordinates (in degrees) of the centre of the grid cell
+    /// with given indices on given face. The grid has `resolution` cells along each edge
+    /// of the face (e.g. 96 for the C96 grid) and cells are indexed from `0` along x and y axes
+    /// of the face, starting from its corner with the lowest coordinates.
+    /// The centre is in the middle of the cell in the coordinates of the projection variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - face is not within 0..6 range.
+    /// - resolution is zero.
+    /// - one or more indices are not within `0..resolution` range.
+    pub fn cell_center(
+        &self,
+        face: u8,
+        i: u32,
+        j: u32,
+        resolution: u32,
+    ) -> Result<(f64, f64), ProjectionError> {
+        ensure_valid_face(face)?;
+
+        if resolution == 0 {
+            return Err(ProjectionError::IncorrectParams(
+                "grid resolution must be positive",
+            ));
+        }
+
+        let cells = f64::from(resolution);
+        let i = f64::from(i);
+        let j = f64::from(j);
+        ensure_within_range!(i, 0.0..cells);
+        ensure_within_range!(j, 0.0..cells);
+
+        let x_c = self.half_width * ((2.0 * i + 1.0) / cells - 1.0);
+        let y_c = self.half_width * ((2.0 * j + 1.0) / cells - 1.0);
+
+        Ok(self.face_to_geographic(self.faces(), usize::from(face), x_c, y_c))
+    }
+
+    const fn faces(&self) -> &'static [Face; 6] {
+        match self.orientation {
+            FaceOrientation::Aligned => &ALIGNED_FACES,
+            FaceOrientation::Fv3 => &FV3_FACES,
+        }
+    }
+
+    /// Finds the face containing the point and computes coordinates on it.
+    fn locate_on_face(&self, faces: &[Face; 6], lon: f64, lat: f64) -> (usize, f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let p = [cos_phi * cos_lambda, cos_phi * sin_lambda, sin_phi];
+
+        // The face with centre closest to the point, centres are the same in all orientations
+        let face = (1..6).fold(0, |best, f| {
+            if dot(p, faces[f][0]) > dot(p, faces[best][0]) {
+                f
+            } else {
+                best
+            }
+        });
+
+        let [center, x_axis, y_axis] = faces[face];
+        let d = dot(p, center);
+
+        (
+            face,
+            self.face_coord(dot(p, x_axis) / d),
+            self.face_coord(dot(p, y_axis) / d),
+        )
+    }
+
+    /// Computes geographical coordinates from coordinates on the face.
+    fn face_to_geographic(&self, faces: &[Face; 6], face: usize, x: f64, y: f64) -> (f64, f64) {
+        let [center, x_axis, y_axis] = faces[face];
+        let t_x = self.tangent_coord(x);
+        let t_y = self.tangent_coord(y);
+
+        let p: [f64; 3] = std::array::from_fn(|k| center[k] + t_x * x_axis[k] + t_y * y_axis[k]);
+
+        let lambda = p[1].atan2(p[0]);
+        let phi = p[2].atan2(p[0].hypot(p[1]));
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+
+    /// Converts coordinate in the tangent plane of the unit sphere to the face coordinate.
+    fn face_coord(&self, t: f64) -> f64 {
+        match self.variant {
+            CubedSphereVariant::Equiangular => self.r * t.atan(),
+            CubedSphereVariant::Equidistant => self.r * t,
+        }
+    }
+
+    /// Converts face coordinate to the coordinate in the tangent plane of the unit sphere.
+    fn tangent_coord(&self, c: f64) -> f64 {
+        match self.variant {
+            CubedSphereVariant::Equiangular => (c / self.r).tan(),
+            CubedSphereVariant::Equidistant => c / self.r,
+        }
+    }
+}
+
+/// Builder struct which allows to construct [`CubedSphere`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CubedSphereBuilder {
+    central_lon: f64,
+    variant: CubedSphereVariant,
+    orientation: FaceOrientation,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for CubedSphereBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            variant: CubedSphereVariant::Equiangular,
+            orientation: FaceOrientation::Aligned,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl CubedSphereBuilder {
+    /// *(optional)* Sets the longitude of the centre of face 0, defaults to `0.0`.
+    /// FV3 grids are commonly shifted 10° westwards, which corresponds to `-10.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets variant of the projection, defaults to [`CubedSphereVariant::Equiangular`].
+    pub const fn variant(&mut self, variant: CubedSphereVariant) -> &mut Self {
+        self.variant = variant;
+        self
+    }
+
+    /// *(optional)* Sets orientation of faces, defaults to [`FaceOrientation::Aligned`].
+    pub const fn orientation(&mut self, orientation: FaceOrientation) -> &mut Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Cubed Sphere projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<CubedSphere, ProjectionError> {
+        let central_lon = self.central_lon;
+        let r = self.ellipsoid.A;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        let half_width = match self.variant {
+            CubedSphereVariant::Equiangular => r * FRAC_PI_4,
+            CubedSphereVariant::Equidistant => r,
+        };
+
+        Ok(CubedSphere {
+            lon_0: central_lon,
+            r,
+            half_width,
+            variant: self.variant,
+            orientation: self.orientation,
+        })
+    }
+}
+
+impl Projection for CubedSphere {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (face, x, y) = self.locate_on_face(&ALIGNED_FACES, lon, lat);
+        let (col, row) = NET_POSITIONS[face];
+        let width = 2.0 * self.half_width;
+
+        (x + f64::from(col) * width, y + f64::from(row) * width)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let width = 2.0 * self.half_width;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let position = ((x / width).round() as i32, (y / width).round() as i32);
+
+        let Some(face) = NET_POSITIONS.iter().position(|&p| p == position) else {
+            return (f64::NAN, f64::NAN);
+        };
+
+        let (col, row) = NET_POSITIONS[face];
+        let x = x - f64::from(col) * width;
+        let y = y - f64::from(row) * width;
+
+        if x.abs().max(y.abs()) > self.half_width * (1.0 + EPS) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        self.face_to_geographic(&ALIGNED_FACES, face, x, y)
+    }
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn ensure_valid_face(face: u8) -> Result<(), ProjectionError> {
+    let face = f64::from(face);
+    ensure_within_range!(face, 0.0..6.0);
+
+    Ok(())
+}
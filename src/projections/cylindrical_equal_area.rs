@@ -0,0 +1,159 @@
+//! The Lambert cylindrical equal-area projection, or Lambert cylindrical projection, is a cylindrical
+//! equal-area projection. With a standard parallel other than the Equator it becomes
+//! one of the named normal cylindrical equal-area projections, such as Behrmann (30°),
+//! Gall–Peters (45°) or the EASE-Grid 2.0 global grid (30° on WGS84, EPSG:6933)
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Lambert_cylindrical_equal-area_projection).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Cylindrical.
+//! - Equal-area.
+//! - Meridians are equally spaced straight lines.
+//! - Parallels are unequally spaced straight lines, farthest apart near the Equator, cutting meridians at right angles.
+//! - Scale is true along the Equator, or along two parallels equidistant from the Equator.
+//! - Shapes are distorted, especially near the poles.
+//! - Used for world maps illustrating area characteristics and for global equal-area grids.
+//! - Presented by Lambert in 1772.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::lambert_azimuthal_equal_area::{phi_from_q, q};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::FRAC_PI_2;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`CylindricalEqualAreaBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CylindricalEqualArea {
+    lon_0: f64,
+    k_0: f64,
+    q_p: f64,
+    ellps: Ellipsoid,
+}
+
+impl CylindricalEqualArea {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> CylindricalEqualAreaBuilder {
+        CylindricalEqualAreaBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`CylindricalEqualArea`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CylindricalEqualAreaBuilder {
+    central_lon: f64,
+    std_parallel: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for CylindricalEqualAreaBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            std_parallel: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl CylindricalEqualAreaBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets standard parallel (latitude) along which scale is true, defaults to `0.0`.
+    /// The same scale is kept along the parallel of opposite sign.
+    /// Use `30.0` for Behrmann projection and EASE-Grid 2.0 global grid.
+    pub const fn standard_parallel(&mut self, std_parallel: f64) -> &mut Self {
+        self.std_parallel = std_parallel;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Cylindrical Equal Area projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - standard parallel is not within -90..90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<CylindricalEqualArea, ProjectionError> {
+        let central_lon = self.central_lon;
+        let std_parallel = self.std_parallel;
+        let ellps = self.ellipsoid;
+        ensure_finite!(central_lon, std_parallel);
+        ensure_within_range!(central_lon, -180.0..180.0);
+        ensure_within_range!(std_parallel, -90.0..90.0);
+
+        let phi_s = std_parallel.to_radians();
+        let k_0 = phi_s.cos()
+            / (ellps.E * phi_s.sin())
+                .mul_add(-(ellps.E * phi_s.sin()), 1.0)
+                .sqrt();
+
+        Ok(CylindricalEqualArea {
+            lon_0: central_lon,
+            k_0,
+            q_p: q(FRAC_PI_2, ellps),
+            ellps,
+        })
+    }
+}
+
+impl Projection for CylindricalEqualArea {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Ellipsoidal formulas from Snyder (1987), which reduce to spherical ones when e = 0
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+
+        let x = self.ellps.A * self.k_0 * lambda;
+        let y = self.ellps.A * q(phi, self.ellps) / (2.0 * self.k_0);
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let q = 2.0 * y * self.k_0 / self.ellps.A;
+
+        // Points beyond the poles are outside of the map
+        if q.abs() > self.q_p * (1.0 + f64::EPSILON) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let lambda = x / (self.ellps.A * self.k_0);
+        let phi = phi_from_q(q, self.ellps);
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
@@ -0,0 +1,201 @@
+//! The Equal Earth map projection is an equal-area pseudocylindrical projection for world maps,
+//! invented by Bojan Šavrič, Bernhard Jenny, and Tom Patterson in 2018. It is inspired by
+//! the widely used Robinson projection, but unlike the Robinson projection, retains the relative
+//! size of areas [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Equal_Earth_projection).
+//!
+//! This implementation follows [Šavrič et al. (2018)](https://doi.org/10.1080/13658816.2018.1504949)
+//! with the ellipsoidal form using authalic latitude (EPSG method 1078).
+//! The inverse solves the polynomial for the parametric latitude with Newton's method.
+//!
+//! Summary:
+//!
+//! - Pseudocylindrical.
+//! - Equal-area.
+//! - Central meridian is a straight line, other meridians are equally spaced polynomial curves.
+//! - Parallels are unequally spaced straight lines, parallel to each other.
+//! - Poles are lines.
+//! - Used for world maps.
+//! - Presented by Šavrič, Jenny and Patterson in 2018.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::lambert_azimuthal_equal_area::{phi_from_q, q};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+const A_1: f64 = 1.340_264;
+const A_2: f64 = -0.081_106;
+const A_3: f64 = 0.000_893;
+const A_4: f64 = 0.003_796;
+
+/// `sqrt(3) / 2`
+const M: f64 = 0.866_025_403_784_438_6;
+
+/// The y coordinate of the pole on the unit sphere.
+const MAX_Y: f64 = 1.317_362_759_157_4;
+
+/// Maximum number of iterations in the inverse projection.
+const MAX_ITERATIONS: usize = 12;
+
+/// Main projection struct that is constructed from [`EqualEarthBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct EqualEarth {
+    lon_0: f64,
+    r_q: f64,
+    q_p: f64,
+    ellps: Ellipsoid,
+}
+
+impl EqualEarth {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> EqualEarthBuilder {
+        EqualEarthBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`EqualEarth`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct EqualEarthBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for EqualEarthBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl EqualEarthBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Equal Earth projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<EqualEarth, ProjectionError> {
+        let central_lon = self.central_lon;
+        let ellps = self.ellipsoid;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        let q_p = q(FRAC_PI_2, ellps);
+
+        Ok(EqualEarth {
+            lon_0: central_lon,
+            r_q: ellps.A * (q_p / 2.0).sqrt(),
+            q_p,
+            ellps,
+        })
+    }
+}
+
+impl Projection for EqualEarth {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+
+        // Sine of authalic latitude, clamped against rounding errors
+        let sin_beta = (q(lat.to_radians(), self.ellps) / self.q_p).clamp(-1.0, 1.0);
+
+        let theta = (M * sin_beta).asin();
+        let theta_2 = theta * theta;
+        let theta_6 = theta_2 * theta_2 * theta_2;
+
+        let x = self.r_q * lambda * theta.cos() / (M * derivative(theta_2, theta_6));
+        let y = self.r_q * theta * polynomial(theta_2, theta_6);
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = x / self.r_q;
+        let y = (y / self.r_q).clamp(-MAX_Y, MAX_Y);
+
+        let mut theta = y;
+        let mut converged = false;
+
+        for _ in 0..MAX_ITERATIONS {
+            let theta_2 = theta * theta;
+            let theta_6 = theta_2 * theta_2 * theta_2;
+
+            let d_theta =
+                theta.mul_add(polynomial(theta_2, theta_6), -y) / derivative(theta_2, theta_6);
+            theta -= d_theta;
+
+            if d_theta.abs() < 1e-11 {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let theta_2 = theta * theta;
+        let theta_6 = theta_2 * theta_2 * theta_2;
+
+        let lambda = M * x * derivative(theta_2, theta_6) / theta.cos();
+        let phi = phi_from_q(self.q_p * theta.sin() / M, self.ellps);
+
+        // Points beyond the edge of the map
+        if lambda.abs() > PI {
+            return (f64::NAN, f64::NAN);
+        }
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Computes `A1 + A2 θ² + θ⁶ (A3 + A4 θ²)`, which multiplied by θ gives the y coordinate.
+fn polynomial(theta_2: f64, theta_6: f64) -> f64 {
+    theta_6.mul_add(A_4.mul_add(theta_2, A_3), A_2.mul_add(theta_2, A_1))
+}
+
+/// Computes derivative of the y coordinate with respect to θ.
+fn derivative(theta_2: f64, theta_6: f64) -> f64 {
+    theta_6.mul_add(
+        (9.0 * A_4).mul_add(theta_2, 7.0 * A_3),
+        (3.0 * A_2).mul_add(theta_2, A_1),
+    )
+}
@@ -0,0 +1,243 @@
+//! The equidistant conic projection is a conic map projection commonly used for maps of
+//! small countries as well as for larger regions such as the continental United States
+//! that are elongated east-to-west. Distances along the meridians are proportionately correct,
+//! as are distances along the standard parallels [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Equidistant_conic_projection).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Conic.
+//! - Equidistant along meridians.
+//! - Parallels are equally spaced arcs of concentric circles.
+//! - Meridians are equally spaced radii of the same circles, thereby cutting parallels at right angles.
+//! - Scale is true along all meridians and along one or two standard parallels.
+//! - Poles are arcs of circles.
+//! - Used for atlas maps of small countries.
+//! - Approximated by Ptolemy (A.D. 150), the present form was developed by De l'Isle about 1745.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::lambert_conformal_conic::{ensure_valid_parallels, m};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use crate::projections::transverse_mercator::rectifying_radius;
+use float_cmp::approx_eq;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`EquidistantConicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct EquidistantConic {
+    lambda_0: f64,
+    n: f64,
+    a_g: f64,
+    rho_0: f64,
+    ellps: Ellipsoid,
+}
+
+impl EquidistantConic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> EquidistantConicBuilder {
+        EquidistantConicBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`EquidistantConic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct EquidistantConicBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    std_parallel_1: Option<f64>,
+    std_parallel_2: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for EquidistantConicBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            std_parallel_1: None,
+            std_parallel_2: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl EquidistantConicBuilder {
+    /// *(required, alternative with [`standard_parallels`](EquidistantConicBuilder::standard_parallels))* Sets first and second standard parallel (latitude) to the same value. Scale is true along that parallel.
+    pub const fn single_parallel(&mut self, standard_parallel: f64) -> &mut Self {
+        self.std_parallel_1 = Some(standard_parallel);
+        self.std_parallel_2 = Some(standard_parallel);
+        self
+    }
+
+    /// *(required, alternative with [`single_parallel`](EquidistantConicBuilder::single_parallel))* Sets first and second standard parallel (latitude). Scale is true along those two standard parallels.
+    pub const fn standard_parallels(
+        &mut self,
+        std_parallel_1: f64,
+        std_parallel_2: f64,
+    ) -> &mut Self {
+        self.std_parallel_1 = Some(std_parallel_1);
+        self.std_parallel_2 = Some(std_parallel_2);
+        self
+    }
+
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// EQDC projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - one or more longitudes are not within -180..180 range.
+    /// - one or more latitudes are not within -90..90 range.
+    /// - one or more arguments are not finite.
+    /// - absolute value of sum of standard parallels is not positive |`std_par_1` + `std_par_2`| == 0.
+    pub fn initialize_projection(&self) -> Result<EquidistantConic, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let std_par_1 = unpack_required_parameter!(self, std_parallel_1);
+        let std_par_2 = unpack_required_parameter!(self, std_parallel_2);
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat, std_par_1, std_par_2);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+        ensure_within_range!(ref_lat, -90.0..90.0);
+        ensure_valid_parallels(std_par_1, std_par_2)?;
+
+        let phi_0 = ref_lat.to_radians();
+        let phi_1 = std_par_1.to_radians();
+        let phi_2 = std_par_2.to_radians();
+
+        let m_1 = m(phi_1, ellps);
+        let m_2 = m(phi_2, ellps);
+        let big_m_0 = meridian_distance(phi_0, ellps);
+        let big_m_1 = meridian_distance(phi_1, ellps);
+        let big_m_2 = meridian_distance(phi_2, ellps);
+
+        // Formulas (16-3) to (16-5) from Snyder (1987)
+        let n = if approx_eq!(f64, std_par_1, std_par_2) {
+            phi_1.sin()
+        } else {
+            ellps.A * (m_1 - m_2) / (big_m_2 - big_m_1)
+        };
+        let a_g = ellps.A.mul_add(m_1 / n, big_m_1);
+        let rho_0 = a_g - big_m_0;
+
+        Ok(EquidistantConic {
+            lambda_0: ref_lon.to_radians(),
+            n,
+            a_g,
+            rho_0,
+            ellps,
+        })
+    }
+}
+
+impl Projection for EquidistantConic {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lambda_0.to_degrees()).to_radians();
+
+        let theta = self.n * lambda;
+        let rho = self.a_g - meridian_distance(phi, self.ellps);
+
+        let x = rho * theta.sin();
+        let y = rho.mul_add(-theta.cos(), self.rho_0);
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let sign = self.n.signum();
+        let rho = sign * x.hypot(self.rho_0 - y);
+        let theta = (x * sign).atan2((self.rho_0 - y) * sign);
+
+        let lambda = (theta / self.n) + self.lambda_0;
+        let phi = phi_from_meridian_distance(self.a_g - rho, self.ellps);
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
+
+/// Computes distance along the meridian from the Equator to latitude `phi`
+/// with series in third flattening from Karney (2011) eq. (14) and (16).
+pub(crate) fn meridian_distance(phi: f64, ellps: Ellipsoid) -> f64 {
+    let n = ellps.F / (2.0 - ellps.F);
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+
+    let coefs = [
+        (-3.0 / 2.0) * n + (9.0 / 16.0) * n3 - (3.0 / 32.0) * n5,
+        (15.0 / 16.0) * n2 - (15.0 / 32.0) * n4 + (135.0 / 2048.0) * n6,
+        (-35.0 / 48.0) * n3 + (105.0 / 256.0) * n5,
+        (315.0 / 512.0) * n4 - (189.0 / 512.0) * n6,
+        (-693.0 / 1280.0) * n5,
+        (1001.0 / 2048.0) * n6,
+    ];
+
+    rectifying_radius(ellps.A, n) * trigonometric_series(phi, &coefs)
+}
+
+/// Computes latitude from distance along the meridian with inverse of the series
+/// used in [`meridian_distance`].
+pub(crate) fn phi_from_meridian_distance(big_m: f64, ellps: Ellipsoid) -> f64 {
+    let n = ellps.F / (2.0 - ellps.F);
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+
+    let coefs = [
+        (3.0 / 2.0) * n - (27.0 / 32.0) * n3 + (269.0 / 512.0) * n5,
+        (21.0 / 16.0) * n2 - (55.0 / 32.0) * n4 + (6759.0 / 4096.0) * n6,
+        (151.0 / 96.0) * n3 - (417.0 / 128.0) * n5,
+        (1097.0 / 512.0) * n4 - (15543.0 / 2560.0) * n6,
+        (8011.0 / 2560.0) * n5,
+        (293_393.0 / 61440.0) * n6,
+    ];
+
+    trigonometric_series(big_m / rectifying_radius(ellps.A, n), &coefs)
+}
+
+/// Computes `x + sum(c_j * sin(2jx))`.
+fn trigonometric_series(x: f64, coefs: &[f64; 6]) -> f64 {
+    (1..).zip(coefs).fold(x, |acc, (j, c_j)| {
+        c_j.mul_add((2.0 * f64::from(j) * x).sin(), acc)
+    })
+}
@@ -5,6 +5,14 @@
 //! Marinus of Tyre, who Ptolemy claims invented the projection about AD 100.
 //! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Equirectangular_projection).
 //!
+//! For ellipsoids other than a sphere, the ellipsoidal form from EPSG method 1028
+//! "Equidistant Cylindrical" is used, in which northing is the meridian arc length
+//! and easting is scaled by the radius of the standard parallel.
+//!
+//! Results for ellipsoids do not match `Proj`'s `eqc`, because it always uses spherical formulas
+//! with the semi-major axis as radius (differences on WGS84 reach tens of kilometres).
+//! The ellipsoidal form is instead verified against the numerical example from EPSG Guidance Note 7-2.
+//!
 //! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
 //!
 //! - Cylindrical.
@@ -13,7 +21,6 @@
 //! - Poles shown as lines.
 //! - Used for world or regional maps.
 //! - Very simple construction.
-//! - Used only in spherical form.
 //! - Presented by Eratosthenes (B.C.) or Marinus (A.D. 100).
 
 use crate::Projection;
@@ -21,6 +28,7 @@ use crate::ellipsoids::Ellipsoid;
 use crate::errors::{
     ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
 };
+use crate::projections::equidistant_conic::{meridian_distance, phi_from_meridian_distance};
 
 #[cfg(feature = "tracing")]
 use tracing::instrument;
@@ -28,12 +36,11 @@ use tracing::instrument;
 /// Main projection struct that is constructed from [`EquidistantCylindricalBuilder`] and used for computations.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub struct EquidistantCylindrical {
-    ref_lat: f64,
     ref_lon: f64,
-    std_par: f64,
 
-    r: f64,
-    r_time_par_cos: f64,
+    m_0: f64,
+    nu_time_par_cos: f64,
+    ellps: Ellipsoid,
 }
 
 impl EquidistantCylindrical {
@@ -55,6 +62,7 @@ pub struct EquidistantCylindricalBuilder {
     ref_lon: Option<f64>,
     ref_lat: Option<f64>,
     std_par: f64,
+    ellipsoid: Ellipsoid,
 }
 
 impl Default for EquidistantCylindricalBuilder {
@@ -63,6 +71,7 @@ impl Default for EquidistantCylindricalBuilder {
             ref_lon: None,
             ref_lat: None,
             std_par: 0.0,
+            ellipsoid: Ellipsoid::SPHERE,
         }
     }
 }
@@ -81,6 +90,13 @@ impl EquidistantCylindricalBuilder {
         self
     }
 
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`SPHERE`](Ellipsoid::SPHERE).
+    /// For other ellipsoids the ellipsoidal form (EPSG method 1028) is used.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
     /// Equirectangular projection constructor.
     ///
     /// To reduce computational overhead of projection functions this
@@ -89,8 +105,6 @@ impl EquidistantCylindricalBuilder {
     /// When projecting multiple coordinates only one instance of the structure should be created
     /// and copied/borrowed as needed.
     ///
-    /// Ellipsoid is not definable as this projection is only defined for sphere.
-    ///
     /// If standard parallel and reference longitude and latitude are 0, then
     /// this projection becomes *Lat-Lon* or *Plate Carrée* projection.
     ///
@@ -105,22 +119,27 @@ impl EquidistantCylindricalBuilder {
         let ref_lon = unpack_required_parameter!(self, ref_lon);
         let ref_lat = unpack_required_parameter!(self, ref_lat);
         let std_par = self.std_par;
+        let ellps = self.ellipsoid;
 
         ensure_finite!(ref_lon, ref_lat, std_par);
         ensure_within_range!(ref_lon, -180.0..180.0);
         ensure_within_range!(ref_lat, -90.0..90.0);
         ensure_within_range!(std_par, -90.0..90.0);
 
-        let r = Ellipsoid::SPHERE.A;
-        let r_time_par_cos = r * std_par.to_radians().cos();
+        // Radius of the standard parallel, which is the radius of curvature
+        // in the prime vertical multiplied by the cosine of its latitude
+        let phi_1 = std_par.to_radians();
+        let nu_time_par_cos = ellps.A * phi_1.cos()
+            / (ellps.E * phi_1.sin())
+                .mul_add(-(ellps.E * phi_1.sin()), 1.0)
+                .sqrt();
 
         Ok(EquidistantCylindrical {
-            ref_lat: ref_lat.to_radians(),
             ref_lon: ref_lon.to_radians(),
-            std_par: std_par.to_radians(),
 
-            r,
-            r_time_par_cos,
+            m_0: meridian_distance(ref_lat.to_radians(), ellps),
+            nu_time_par_cos,
+            ellps,
         })
     }
 }
@@ -132,8 +151,8 @@ impl Projection for EquidistantCylindrical {
         let lon = lon.to_radians();
         let lat = lat.to_radians();
 
-        let x = self.r_time_par_cos * (lon - self.ref_lon);
-        let y = self.r * (lat - self.ref_lat);
+        let x = self.nu_time_par_cos * (lon - self.ref_lon);
+        let y = meridian_distance(lat, self.ellps) - self.m_0;
 
         (x, y)
     }
@@ -141,8 +160,8 @@ impl Projection for EquidistantCylindrical {
     #[inline]
     #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
     fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
-        let lon = (x / self.r_time_par_cos) + self.ref_lon;
-        let lat = (y / self.r) + self.ref_lat;
+        let lon = (x / self.nu_time_par_cos) + self.ref_lon;
+        let lat = phi_from_meridian_distance(y + self.m_0, self.ellps);
 
         (lon.to_degrees(), lat.to_degrees())
     }
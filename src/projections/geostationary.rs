@@ -0,0 +1,303 @@
+//! The geostationary satellite view projection pictures how a geostationary satellite
+//! scans the Earth at regular scanning angle intervals. It is used by imagers
+//! of geostationary weather satellites such as GOES-R ABI or Meteosat SEVIRI
+//! (CF `geostationary` grid mapping, GRIB2 template 3.90) [(Proj documentation)](https://proj.org/operations/projections/geos.html).
+//!
+//! Projected coordinates are scanning angles of the satellite instrument,
+//! returned either directly in radians or multiplied by the satellite height (in meters),
+//! which is the convention used by Proj and CF.
+//!
+//! Points not visible from the satellite return [`ProjectionError::PointNotVisible`]
+//! from checked [`project`](Projection::project), and points outside of the Earth disk
+//! return [`ProjectionError::OffDisk`] from checked [`inverse_project`](Projection::inverse_project).
+//!
+//! Summary:
+//!
+//! - Azimuthal perspective projection from the point above the Equator.
+//! - Neither conformal nor equal-area.
+//! - Less than one hemisphere is shown.
+//! - Angles between the meridian and the scan direction depend on the sweep angle axis of the instrument.
+//! - Used for imagery of geostationary weather satellites.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::oblique_lon_lat::adjust_lon;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Axis around which the instrument mirror sweeps during the scan.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum SweepAxis {
+    /// Sweep angle around x axis, used by GOES imagers (equivalent to `+sweep=x` in Proj).
+    X,
+
+    /// Sweep angle around y axis, used by Meteosat imagers (equivalent to `+sweep=y` in Proj).
+    #[default]
+    Y,
+}
+
+/// Units of projected coordinates.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum ScanUnits {
+    /// Scanning angles multiplied by satellite height, as used by Proj and CF conventions.
+    #[default]
+    Meters,
+
+    /// Scanning angles in radians, as used in GOES-R ABI fixed grid.
+    Radians,
+}
+
+/// Main projection struct that is constructed from [`GeostationaryBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Geostationary {
+    lambda_0: f64,
+    radius_g: f64,
+    c: f64,
+    radius_p: f64,
+    scale: f64,
+    sweep: SweepAxis,
+}
+
+impl Geostationary {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> GeostationaryBuilder {
+        GeostationaryBuilder::default()
+    }
+
+    /// Computes geocentric position vector of the point on the unit ellipsoid.
+    fn view_vector(&self, lon: f64, lat: f64) -> (f64, f64, f64) {
+        let lambda = lon.to_radians() - self.lambda_0;
+        let phi = lat.to_radians();
+
+        // Geocentric latitude
+        let phi_c = (self.radius_p.powi(2) * phi.tan()).atan();
+        let (sin_phi_c, cos_phi_c) = phi_c.sin_cos();
+        let r = self.radius_p / (self.radius_p * cos_phi_c).hypot(sin_phi_c);
+
+        (
+            r * lambda.cos() * cos_phi_c,
+            r * lambda.sin() * cos_phi_c,
+            r * sin_phi_c,
+        )
+    }
+
+    /// Computes direction of the scan from the satellite for given projected coordinates.
+    fn scan_vector(&self, x: f64, y: f64) -> (f64, f64, f64) {
+        let x = x / self.scale;
+        let y = y / self.scale;
+
+        match self.sweep {
+            SweepAxis::X => {
+                let v_z = y.tan();
+                (-1.0, x.tan() * 1.0f64.hypot(v_z), v_z)
+            }
+            SweepAxis::Y => {
+                let v_y = x.tan();
+                (-1.0, v_y, y.tan() * 1.0f64.hypot(v_y))
+            }
+        }
+    }
+
+    /// Computes discriminant of the equation for intersection of the scan direction with the ellipsoid,
+    /// which is negative when the scan direction misses the Earth.
+    fn ray_discriminant(&self, v_x: f64, v_y: f64, v_z: f64) -> f64 {
+        let a = (v_z / self.radius_p).mul_add(v_z / self.radius_p, v_x.mul_add(v_x, v_y * v_y));
+        let b = 2.0 * self.radius_g * v_x;
+
+        b.mul_add(b, -4.0 * a * self.c)
+    }
+}
+
+/// Builder struct which allows to construct [`Geostationary`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct GeostationaryBuilder {
+    sub_satellite_lon: f64,
+    height: Option<f64>,
+    sweep: SweepAxis,
+    units: ScanUnits,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for GeostationaryBuilder {
+    fn default() -> Self {
+        Self {
+            sub_satellite_lon: 0.0,
+            height: None,
+            sweep: SweepAxis::Y,
+            units: ScanUnits::Meters,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl GeostationaryBuilder {
+    /// *(required)* Sets height of the satellite above the surface in meters
+    /// (eg. `35_786_023.0` for GOES-R, `35_785_831.0` for Meteosat).
+    pub const fn satellite_height(&mut self, height: f64) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// *(optional)* Sets longitude of the sub-satellite point, defaults to `0.0`.
+    pub const fn sub_satellite_lon(&mut self, lon: f64) -> &mut Self {
+        self.sub_satellite_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets sweep angle axis of the instrument, defaults to [`SweepAxis::Y`].
+    pub const fn sweep_axis(&mut self, sweep: SweepAxis) -> &mut Self {
+        self.sweep = sweep;
+        self
+    }
+
+    /// *(optional)* Sets units of projected coordinates, defaults to [`ScanUnits::Meters`].
+    pub const fn units(&mut self, units: ScanUnits) -> &mut Self {
+        self.units = units;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Geostationary projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - sub-satellite longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    /// - satellite height is not positive.
+    pub fn initialize_projection(&self) -> Result<Geostationary, ProjectionError> {
+        let height = unpack_required_parameter!(self, height);
+        let sub_satellite_lon = self.sub_satellite_lon;
+        let ellps = self.ellipsoid;
+        ensure_finite!(height, sub_satellite_lon);
+
+        ensure_within_range!(sub_satellite_lon, -180.0..180.0);
+
+        if height <= 0.0 {
+            return Err(ProjectionError::IncorrectParams(
+                "satellite height must be positive",
+            ));
+        }
+
+        let radius_g_1 = height / ellps.A;
+        let radius_g = 1.0 + radius_g_1;
+
+        // Coordinates are computed on the ellipsoid scaled to unit semi-major axis
+        // and then scaled to requested units
+        let scale = match self.units {
+            ScanUnits::Meters => height,
+            ScanUnits::Radians => 1.0,
+        };
+
+        Ok(Geostationary {
+            lambda_0: sub_satellite_lon.to_radians(),
+            radius_g,
+            c: radius_g.mul_add(radius_g, -1.0),
+            radius_p: ellps.B / ellps.A,
+            scale,
+            sweep: self.sweep,
+        })
+    }
+}
+
+impl Projection for Geostationary {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::PointNotVisible`] when the point is not visible from the satellite.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        let (v_x, v_y, v_z) = self.view_vector(lon, lat);
+
+        if (self.radius_g - v_x).mul_add(v_x, -(v_y * v_y)) - (v_z / self.radius_p).powi(2) < 0.0 {
+            return Err(ProjectionError::PointNotVisible(lon, lat));
+        }
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::OffDisk`] when the point is outside of the Earth disk.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        let (v_x, v_y, v_z) = self.scan_vector(x, y);
+
+        if self.ray_discriminant(v_x, v_y, v_z) < 0.0 {
+            return Err(ProjectionError::OffDisk(x, y));
+        }
+
+        let (lon, lat) = self.inverse_project_unchecked(x, y);
+
+        if !lon.is_finite() || !lat.is_finite() {
+            Err(ProjectionError::InverseProjectionImpossible(x, y))
+        } else {
+            Ok((lon, lat))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (v_x, v_y, v_z) = self.view_vector(lon, lat);
+        let tmp = self.radius_g - v_x;
+
+        let (x, y) = match self.sweep {
+            SweepAxis::X => ((v_y / v_z.hypot(tmp)).atan(), (v_z / tmp).atan()),
+            SweepAxis::Y => ((v_y / tmp).atan(), (v_z / v_y.hypot(tmp)).atan()),
+        };
+
+        (x * self.scale, y * self.scale)
+    }
+
+    #[inline]
+    #[allow(clippy::many_single_char_names)]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let (v_x, v_y, v_z) = self.scan_vector(x, y);
+
+        // Distance from the satellite to the Earth surface along the scan direction
+        let a = (v_z / self.radius_p).mul_add(v_z / self.radius_p, v_x.mul_add(v_x, v_y * v_y));
+        let b = 2.0 * self.radius_g * v_x;
+        let k = (-b - self.ray_discriminant(v_x, v_y, v_z).sqrt()) / (2.0 * a);
+
+        let s_x = k.mul_add(v_x, self.radius_g);
+        let s_y = k * v_y;
+        let s_z = k * v_z;
+
+        let lambda = s_y.atan2(s_x);
+        let phi = (s_z * lambda.cos() / s_x / self.radius_p.powi(2)).atan();
+
+        (
+            adjust_lon((lambda + self.lambda_0).to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
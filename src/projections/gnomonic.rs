@@ -0,0 +1,285 @@
+//! A gnomonic map projection is a map projection which displays all great circles as straight lines,
+//! resulting in any straight line segment on a gnomonic map showing a geodesic, the shortest route
+//! between the segment's two endpoints. This is achieved by casting surface points of the sphere
+//! onto a tangent plane, each landing where a ray from the center of the sphere passes through
+//! the point on the surface and then on to the plane [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Gnomonic_projection).
+//!
+//! For the sphere, formulas from Snyder (1987) are used. For the ellipsoid, this implementation
+//! follows the ellipsoidal gnomonic projection by [Karney (2013)](https://doi.org/10.1007/s00190-012-0578-z),
+//! in which geodesics passing through the center are straight lines and other geodesics
+//! are very nearly straight. Its inverse is computed iteratively with bounded number of iterations.
+//!
+//! Points 90° or more from the center return [`ProjectionError::PointNotVisible`]
+//! from checked [`project`](Projection::project).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Azimuthal.
+//! - All meridians and the Equator are straight lines.
+//! - All other parallels are straight lines, circles, ellipses, parabolas, or hyperbolas, depending on the aspect.
+//! - Neither conformal nor equal-area.
+//! - Any straight line drawn on the map is on a great circle.
+//! - Directions from the center are true.
+//! - Less than one hemisphere can be shown.
+//! - Used to show great circle paths as straight lines and thus to assist navigators and aviators.
+//! - Possibly derived by Thales (c. 580 B.C.).
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Maximum number of iterations in the ellipsoidal inverse projection.
+const MAX_ITERATIONS: usize = 10;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::large_enum_variant)]
+enum Form {
+    Spherical {
+        sin_phi_0: f64,
+        cos_phi_0: f64,
+        r: f64,
+    },
+    Ellipsoidal {
+        geod: Geodesic,
+    },
+}
+
+/// Main projection struct that is constructed from [`GnomonicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Gnomonic {
+    lon_0: f64,
+    lat_0: f64,
+    form: Form,
+}
+
+impl Gnomonic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> GnomonicBuilder {
+        GnomonicBuilder::default()
+    }
+
+    /// Solves for the distance along the geodesic from the center, at which `m12/M12`
+    /// is equal to `rho`, with Newton's method as described by Karney (2013).
+    fn ellipsoidal_inverse(&self, geod: &Geodesic, x: f64, y: f64, rho: f64) -> (f64, f64) {
+        let a = geod.a;
+        let azi1 = x.atan2(y).to_degrees();
+        let little = rho <= a;
+        let rho = if little { rho } else { 1.0 / rho };
+        let tolerance = 0.01 * f64::EPSILON.sqrt() * a;
+
+        let mut s_12 = a * (x.hypot(y) / a).atan();
+
+        for _ in 0..MAX_ITERATIONS {
+            let (_, _, _, m_12, big_m_12, _) = geod.direct(self.lat_0, self.lon_0, azi1, s_12);
+
+            // If little, solve rho(s) = rho with drho(s)/ds = 1/M^2,
+            // otherwise solve 1/rho(s) = 1/rho with d(1/rho(s))/ds = -1/m^2
+            let ds = if little {
+                rho.mul_add(-big_m_12, m_12) * big_m_12
+            } else {
+                rho.mul_add(m_12, -big_m_12) * m_12
+            };
+            s_12 -= ds;
+
+            if ds.abs() < tolerance {
+                let (lat, lon, _, _, _, _) = geod.direct(self.lat_0, self.lon_0, azi1, s_12);
+                return (lon, lat);
+            }
+        }
+
+        (f64::NAN, f64::NAN)
+    }
+}
+
+/// Builder struct which allows to construct [`Gnomonic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct GnomonicBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for GnomonicBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl GnomonicBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// When the ellipsoid is a sphere, faster spherical formulas are used.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Gnomonic projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - reference longitude is not within -180..180 range.
+    /// - reference latitude is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Gnomonic, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&ref_lat) {
+            return Err(ProjectionError::ParamOutOfRange("ref_lat", -90.0, 90.0));
+        }
+
+        let form = if approx_eq!(f64, ellps.E, 0.0) {
+            Form::Spherical {
+                sin_phi_0: ref_lat.to_radians().sin(),
+                cos_phi_0: ref_lat.to_radians().cos(),
+                r: ellps.A,
+            }
+        } else {
+            Form::Ellipsoidal { geod: ellps.into() }
+        };
+
+        Ok(Gnomonic {
+            lon_0: ref_lon,
+            lat_0: ref_lat,
+            form,
+        })
+    }
+}
+
+impl Projection for Gnomonic {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::PointNotVisible`] when the point is 90° or more from the center of projection.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        let visible = match self.form {
+            Form::Spherical {
+                sin_phi_0,
+                cos_phi_0,
+                ..
+            } => cos_c(lon - self.lon_0, lat, sin_phi_0, cos_phi_0) > f64::EPSILON,
+            Form::Ellipsoidal { geod } => {
+                let (_, _, _, big_m_12, _, _) = geod.inverse(self.lat_0, self.lon_0, lat, lon);
+                big_m_12 > f64::EPSILON
+            }
+        };
+
+        if !visible {
+            return Err(ProjectionError::PointNotVisible(lon, lat));
+        }
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        match self.form {
+            Form::Spherical {
+                sin_phi_0,
+                cos_phi_0,
+                r,
+            } => {
+                // Formulas (22-3) to (22-5) from Snyder (1987)
+                let phi = lat.to_radians();
+                let lambda = (lon - self.lon_0).to_radians();
+                let k = r / cos_c(lon - self.lon_0, lat, sin_phi_0, cos_phi_0);
+
+                let x = k * phi.cos() * lambda.sin();
+                let y = k * cos_phi_0.mul_add(phi.sin(), -(sin_phi_0 * phi.cos() * lambda.cos()));
+
+                (x, y)
+            }
+            Form::Ellipsoidal { geod } => {
+                let (_, azi1, _, m_12, big_m_12, _, _) =
+                    geod.inverse(self.lat_0, self.lon_0, lat, lon);
+
+                let rho = m_12 / big_m_12;
+                let azi1 = azi1.to_radians();
+
+                (rho * azi1.sin(), rho * azi1.cos())
+            }
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = x.hypot(y);
+
+        if approx_eq!(f64, rho, 0.0) {
+            return (self.lon_0, self.lat_0);
+        }
+
+        match self.form {
+            Form::Spherical {
+                sin_phi_0,
+                cos_phi_0,
+                r,
+            } => {
+                // Formulas (22-14) to (22-16) from Snyder (1987)
+                let c = (rho / r).atan();
+                let (sin_c, cos_c) = c.sin_cos();
+
+                let phi = cos_c.mul_add(sin_phi_0, y * sin_c * cos_phi_0 / rho).asin();
+                let lambda =
+                    (x * sin_c).atan2((rho * cos_phi_0).mul_add(cos_c, -(y * sin_phi_0 * sin_c)));
+
+                (
+                    adjust_lon(self.lon_0 + lambda.to_degrees()),
+                    phi.to_degrees(),
+                )
+            }
+            Form::Ellipsoidal { geod } => self.ellipsoidal_inverse(&geod, x, y, rho),
+        }
+    }
+}
+
+/// Cosine of the angular distance from the center of projection, Snyder (1987) eq. (5-3).
+fn cos_c(d_lon: f64, lat: f64, sin_phi_0: f64, cos_phi_0: f64) -> f64 {
+    let phi = lat.to_radians();
+    sin_phi_0.mul_add(phi.sin(), cos_phi_0 * phi.cos() * d_lon.to_radians().cos())
+}
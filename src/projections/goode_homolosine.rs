@@ -0,0 +1,183 @@
+//! The Goode homolosine projection is a pseudocylindrical, equal-area, composite map projection
+//! used for world maps. It combines the sinusoidal projection between latitudes 40°44′11.8″ N and S
+//! with the Mollweide projection poleward of them, shifted so that the two parts meet.
+//! It is normally presented with multiple interruptions, which can be constructed with
+//! [`GoodeHomolosine::interrupted`] [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Goode_homolosine_projection).
+//!
+//! This implementation follows the interrupted Goode homolosine `igh` projection from `Proj`,
+//! in which the parts are joined exactly at the latitude of the transition.
+//!
+//! Summary by [Snyder (1993)](https://press.uchicago.edu/ucp/books/book/chicago/F/bo3632853.html):
+//!
+//! - Pseudocylindrical.
+//! - Equal-area.
+//! - Parallels are straight lines; meridians are sinusoids between 40°44′ N and S and elliptical arcs poleward.
+//! - Usually interrupted to reduce distortion of continents or oceans.
+//! - Used for world maps, especially of global land-cover products.
+//! - Presented by Goode in 1923.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::Hemisphere;
+use crate::projections::interrupted::{Interrupted, Lobe};
+use crate::projections::mollweide::{unit_mollweide, unit_mollweide_inverse};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::PI;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Latitude (in degrees) of the transition between sinusoidal and Mollweide parts, 40°44′11.8″.
+const PHI_LIMIT: f64 = 40.0 + 44.0 / 60.0 + 11.8 / 3600.0;
+
+/// Lobes of the Interrupted Goode Homolosine projection, interrupted in the oceans
+/// as in the original map by Goode (1925) and `igh` projection from `Proj`.
+pub const INTERRUPTED_LOBES: [Lobe; 6] = [
+    Lobe::new(-180.0, -40.0, -100.0, Hemisphere::North),
+    Lobe::new(-40.0, 180.0, 30.0, Hemisphere::North),
+    Lobe::new(-180.0, -100.0, -160.0, Hemisphere::South),
+    Lobe::new(-100.0, -20.0, -60.0, Hemisphere::South),
+    Lobe::new(-20.0, 80.0, 20.0, Hemisphere::South),
+    Lobe::new(80.0, 180.0, 140.0, Hemisphere::South),
+];
+
+/// Main projection struct that is constructed from [`GoodeHomolosineBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct GoodeHomolosine {
+    lon_0: f64,
+    r: f64,
+    phi_limit: f64,
+    y_offset: f64,
+}
+
+impl GoodeHomolosine {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> GoodeHomolosineBuilder {
+        GoodeHomolosineBuilder::default()
+    }
+
+    /// Constructs the Interrupted Goode Homolosine projection with [`INTERRUPTED_LOBES`],
+    /// equivalent to `igh` projection from `Proj`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] when the base projection cannot be constructed for given ellipsoid.
+    pub fn interrupted(ellps: Ellipsoid) -> Result<Interrupted<Self, 6>, ProjectionError> {
+        let base = Self::builder().ellipsoid(ellps).initialize_projection()?;
+
+        Interrupted::builder()
+            .base_projection(base)
+            .lobes(INTERRUPTED_LOBES)
+            .initialize_projection()
+    }
+}
+
+/// Builder struct which allows to construct [`GoodeHomolosine`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct GoodeHomolosineBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for GoodeHomolosineBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl GoodeHomolosineBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Goode Homolosine projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<GoodeHomolosine, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        // Mollweide part is shifted to meet the sinusoidal part at the transition latitude
+        let phi_limit = PHI_LIMIT.to_radians();
+        let y_offset = unit_mollweide(0.0, phi_limit).1 - phi_limit;
+
+        Ok(GoodeHomolosine {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+            phi_limit,
+            y_offset,
+        })
+    }
+}
+
+impl Projection for GoodeHomolosine {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+
+        let (x, y) = if phi.abs() <= self.phi_limit {
+            (lambda * phi.cos(), phi)
+        } else {
+            let (x, y) = unit_mollweide(lambda, phi);
+            (x, y - self.y_offset.copysign(phi))
+        };
+
+        (self.r * x, self.r * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = x / self.r;
+        let y = y / self.r;
+
+        let (lambda, phi) = if y.abs() <= self.phi_limit {
+            (x / y.cos(), y)
+        } else {
+            unit_mollweide_inverse(x, y + self.y_offset.copysign(y))
+        };
+
+        if lambda.abs() > PI {
+            return (f64::NAN, f64::NAN);
+        }
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
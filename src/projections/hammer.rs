@@ -0,0 +1,153 @@
+//! The Hammer projection is an equal-area map projection described by Ernst Hammer in 1892.
+//! Using the same 2:1 elliptical outer shape as the Mollweide projection, Hammer intended
+//! to reduce distortion in the regions of the outer meridians, where it is extreme in the Mollweide.
+//! It is derived from the equatorial aspect of the Lambert azimuthal equal-area projection
+//! by halving longitudes and doubling the resulting x coordinates
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Hammer_projection).
+//!
+//! Inverse projection is found iteratively with Newton's method. Points outside of the elliptical
+//! outline of the map have no solution, so checked [`inverse_project`](Projection::inverse_project)
+//! returns [`ProjectionError::InverseNotConverged`] for them.
+//!
+//! Summary by [Snyder (1993)](https://press.uchicago.edu/ucp/books/book/chicago/F/bo3632853.html):
+//!
+//! - Modified azimuthal.
+//! - Equal-area.
+//! - Central meridian and Equator are straight lines, other meridians and parallels are curves.
+//! - Outline of the world map is an ellipse, with the Equator twice as long as the central meridian.
+//! - Used for world maps.
+//! - Presented by Hammer in 1892.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::numerical_inverse;
+use crate::projections::oblique_lon_lat::adjust_lon;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`HammerBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Hammer {
+    lon_0: f64,
+    r: f64,
+}
+
+impl Hammer {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> HammerBuilder {
+        HammerBuilder::default()
+    }
+
+    /// Computes the inverse projection numerically, returns `None` when it does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let x = x / self.r;
+        let y = y / self.r;
+        let (lambda, phi) = numerical_inverse::solve(unit_hammer, x, y, (x, y))?;
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+}
+
+/// Builder struct which allows to construct [`Hammer`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct HammerBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for HammerBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl HammerBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Hammer projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Hammer, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(Hammer {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for Hammer {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the numerical inverse does not converge,
+    /// which also happens for points outside of the map.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let (x, y) = unit_hammer(lambda, lat.to_radians());
+
+        (self.r * x, self.r * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
+
+/// Computes coordinates of the Hammer projection on the unit sphere,
+/// formulas from Snyder (1993).
+fn unit_hammer(lambda: f64, phi: f64) -> (f64, f64) {
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    let (sin_half_lambda, cos_half_lambda) = (lambda / 2.0).sin_cos();
+    let d = (2.0 / cos_phi.mul_add(cos_half_lambda, 1.0)).sqrt();
+
+    (2.0 * d * cos_phi * sin_half_lambda, d * sin_phi)
+}
@@ -0,0 +1,346 @@
+//! The `HEALPix` (Hierarchical Equal Area isoLatitude Pixelization) projection is an equal-area projection
+//! of the `HEALPix` discrete global grid, which divides the sphere into 12 base cells of equal area,
+//! each divided into 4 cells at every next resolution. It combines the Lambert cylindrical equal-area
+//! projection between latitudes ±41.81° with the interrupted Collignon projection in polar regions,
+//! so that all cells of the grid are squares (rotated by 45°) in the projection plane
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/HEALPix).
+//!
+//! This implementation follows `healpix` projection from `Proj`. On the ellipsoid the authalic
+//! latitude is projected onto the sphere of the same surface area.
+//!
+//! Cells of the grid can be identified with [`Healpix::cell_id`] and [`Healpix::cell_center`],
+//! using the NESTED numbering scheme by [Górski et al. (2005)](https://doi.org/10.1086/427976).
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::lambert_azimuthal_equal_area::{phi_from_q, q};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Maximum resolution of the grid, at which cell identifiers still fit into `u64`.
+pub const MAX_RESOLUTION: u8 = 29;
+
+/// Tolerance of the projected coordinates (on the unit sphere) for which point is still considered to be on the map.
+pub(crate) const EPS: f64 = 1e-12;
+
+/// Main projection struct that is constructed from [`HealpixBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Healpix {
+    lon_0: f64,
+    r_q: f64,
+    q_p: f64,
+    ellps: Ellipsoid,
+}
+
+impl Healpix {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> HealpixBuilder {
+        HealpixBuilder::default()
+    }
+
+    /// Returns the identifier of the cell containing given projected point at given resolution,
+    /// in the NESTED numbering scheme. Base cells are numbered from the central meridian eastwards,
+    /// first four in the north, then four on the Equator and last four in the south,
+    /// so with central meridian at 0° identifiers are the same as in the `HEALPix` library.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - resolution is larger than [`MAX_RESOLUTION`].
+    /// - point is outside of the map.
+    /// - one or more arguments are not finite.
+    pub fn cell_id(&self, x: f64, y: f64, resolution: u8) -> Result<u64, ProjectionError> {
+        ensure_finite!(x, y);
+        ensure_valid_resolution(resolution)?;
+
+        let x_u = x / self.r_q;
+        let y_u = y / self.r_q;
+
+        if !in_image(x_u, y_u) {
+            return Err(ProjectionError::OutOfDomain(x, y));
+        }
+
+        // Base cells are centered at points (m, n) in units of π/4
+        let x_q = x_u / FRAC_PI_4;
+        let y_q = y_u / FRAC_PI_4;
+        let (m, n) = base_cell_center(x_q, y_q);
+
+        // Position within the base cell along its south-east and south-west edges
+        let du = x_q - f64::from(m);
+        let dv = y_q - f64::from(n);
+        let nside = 1_u32 << resolution;
+        let ix = cell_index(f64::midpoint(du, dv) + 0.5, nside);
+        let iy = cell_index((dv - du) / 2.0 + 0.5, nside);
+
+        let base_cell = match n {
+            1 => ((m - 1) / 2).rem_euclid(4),
+            0 => (m / 2).rem_euclid(4) + 4,
+            _ => ((m - 1) / 2).rem_euclid(4) + 8,
+        };
+
+        Ok(u64::from(base_cell.unsigned_abs()) * u64::from(nside).pow(2) + interleave(ix, iy))
+    }
+
+    /// Returns longitude and latitude of the center of the cell with given identifier
+    /// in the NESTED numbering scheme at given resolution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - resolution is larger than [`MAX_RESOLUTION`].
+    /// - there is no cell with given identifier at given resolution.
+    pub fn cell_center(&self, cell_id: u64, resolution: u8) -> Result<(f64, f64), ProjectionError> {
+        ensure_valid_resolution(resolution)?;
+
+        let nside = 1_u32 << resolution;
+        let cells_in_base = u64::from(nside).pow(2);
+
+        if cell_id >= 12 * cells_in_base {
+            return Err(ProjectionError::InvalidCellId(cell_id, resolution));
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let base_cell = (cell_id / cells_in_base) as i32;
+        let (ix, iy) = deinterleave(cell_id % cells_in_base);
+
+        let n = 1 - base_cell / 4;
+        let m = 2 * (base_cell % 4) + n.abs();
+        let m = if m > 4 { m - 8 } else { m };
+
+        let ds = (f64::from(ix) + 0.5) / f64::from(nside) - 0.5;
+        let dt = (f64::from(iy) + 0.5) / f64::from(nside) - 0.5;
+        let u = f64::from(m) + ds - dt;
+        let v = f64::from(n) + ds + dt;
+        let u = if u > 4.0 { u - 8.0 } else { u };
+
+        self.inverse_project(u * FRAC_PI_4 * self.r_q, v * FRAC_PI_4 * self.r_q)
+    }
+}
+
+/// Builder struct which allows to construct [`Healpix`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct HealpixBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for HealpixBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl HealpixBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// `HEALPix` projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Healpix, ProjectionError> {
+        let central_lon = self.central_lon;
+        let ellps = self.ellipsoid;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        let q_p = q(FRAC_PI_2, ellps);
+
+        Ok(Healpix {
+            lon_0: central_lon,
+            r_q: authalic_radius(q_p, ellps),
+            q_p,
+            ellps,
+        })
+    }
+}
+
+impl Projection for Healpix {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let beta = authalic_lat(lat.to_radians(), self.q_p, self.ellps);
+
+        let (x, y) = unit_healpix(lambda, beta);
+
+        (self.r_q * x, self.r_q * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let (lambda, beta) = unit_healpix_inverse(x / self.r_q, y / self.r_q);
+        let phi = lat_from_authalic(beta, self.q_p, self.ellps);
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Computes radius of the sphere with the same surface area as the ellipsoid.
+pub(crate) fn authalic_radius(q_p: f64, ellps: Ellipsoid) -> f64 {
+    ellps.A * (q_p / 2.0).sqrt()
+}
+
+/// Computes authalic latitude, which is equal to latitude on the sphere.
+pub(crate) fn authalic_lat(phi: f64, q_p: f64, ellps: Ellipsoid) -> f64 {
+    (q(phi, ellps) / q_p).clamp(-1.0, 1.0).asin()
+}
+
+/// Computes latitude from authalic latitude.
+pub(crate) fn lat_from_authalic(beta: f64, q_p: f64, ellps: Ellipsoid) -> f64 {
+    phi_from_q(q_p * beta.sin(), ellps)
+}
+
+/// Computes coordinates of the `HEALPix` projection on the unit sphere.
+pub(crate) fn unit_healpix(lambda: f64, phi: f64) -> (f64, f64) {
+    let sin_phi = phi.sin();
+
+    if sin_phi.abs() <= 2.0 / 3.0 {
+        return (lambda, 3.0 * PI / 8.0 * sin_phi);
+    }
+
+    let sigma = (3.0 * (1.0 - sin_phi.abs())).sqrt();
+    let lambda_c = cap_center(lambda);
+
+    (
+        (lambda - lambda_c).mul_add(sigma, lambda_c),
+        (FRAC_PI_4 * (2.0 - sigma)).copysign(phi),
+    )
+}
+
+/// Computes longitude and latitude on the unit sphere from coordinates of the `HEALPix` projection,
+/// returns NaNs when point is outside of the map.
+pub(crate) fn unit_healpix_inverse(x: f64, y: f64) -> (f64, f64) {
+    if !in_image(x, y) {
+        return (f64::NAN, f64::NAN);
+    }
+
+    if y.abs() <= FRAC_PI_4 {
+        return (x, (8.0 * y / (3.0 * PI)).clamp(-1.0, 1.0).asin());
+    }
+
+    let x_c = cap_center(x);
+    let tau = 2.0 - 4.0 * y.abs() / PI;
+
+    // All points at the pole are projected to the tip of the cap
+    if tau <= EPS {
+        return (x_c, FRAC_PI_2.copysign(y));
+    }
+
+    let lambda = x_c + (x - x_c) / tau;
+    let phi = (1.0 - tau * tau / 3.0).asin().copysign(y);
+
+    (lambda.clamp(-PI, PI), phi)
+}
+
+/// Returns the central meridian of the polar cap containing given longitude or easting.
+fn cap_center(x: f64) -> f64 {
+    let cap = (2.0 * x / PI + 2.0).floor().clamp(0.0, 3.0);
+
+    FRAC_PI_2.mul_add(cap, -3.0 * FRAC_PI_4)
+}
+
+/// Checks if point on the unit sphere is within the image of the `HEALPix` projection.
+fn in_image(x: f64, y: f64) -> bool {
+    if x.abs() > PI + EPS || y.abs() > FRAC_PI_2 + EPS {
+        return false;
+    }
+
+    y.abs() <= FRAC_PI_4 || (x - cap_center(x)).abs() <= FRAC_PI_2 - y.abs() + EPS
+}
+
+/// Returns the center of the base cell containing given point, in units of π/4.
+/// Centers are at points with even sum of coordinates in three rows: north, equatorial and south.
+fn base_cell_center(u: f64, v: f64) -> (i32, i32) {
+    let mut center = (0, 0);
+    let mut min_distance = f64::INFINITY;
+
+    for n in [1, 0, -1] {
+        let n_f = f64::from(n);
+        let m_f = 2.0f64.mul_add(((u - n_f) / 2.0).round(), n_f);
+        let distance = (u - m_f).abs() + (v - n_f).abs();
+
+        if distance < min_distance {
+            min_distance = distance;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let m = m_f as i32;
+            center = (m, n);
+        }
+    }
+
+    center
+}
+
+/// Returns the index of the cell along the edge of the base cell, from the position within 0..=1.
+pub(crate) fn cell_index(position: f64, nside: u32) -> u32 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let index = (position.max(0.0) * f64::from(nside)).floor() as u32;
+
+    index.min(nside - 1)
+}
+
+/// Interleaves bits of indices, with bits of `ix` on even positions.
+fn interleave(ix: u32, iy: u32) -> u64 {
+    (0..32).fold(0, |id, bit| {
+        id | u64::from((ix >> bit) & 1) << (2 * bit) | u64::from((iy >> bit) & 1) << (2 * bit + 1)
+    })
+}
+
+/// Splits bits of cell identifier within the base cell into indices, inverse of [`interleave`].
+fn deinterleave(id: u64) -> (u32, u32) {
+    (0..32).fold((0, 0), |(ix, iy), bit| {
+        #[allow(clippy::cast_possible_truncation)]
+        let (x_bit, y_bit) = (
+            ((id >> (2 * bit)) & 1) as u32,
+            ((id >> (2 * bit + 1)) & 1) as u32,
+        );
+
+        (ix | x_bit << bit, iy | y_bit << bit)
+    })
+}
+
+fn ensure_valid_resolution(resolution: u8) -> Result<(), ProjectionError> {
+    let resolution = f64::from(resolution);
+    ensure_within_range!(resolution, 0.0..f64::from(MAX_RESOLUTION) + 1.0);
+
+    Ok(())
+}
@@ -0,0 +1,463 @@
+//! The Hotine oblique Mercator projection is a conformal cylindrical projection in which
+//! the cylinder touches the ellipsoid along a great circle (initial line) at an arbitrary azimuth.
+//! It is used by national grids of regions elongated in an oblique direction,
+//! such as Malaysia (Rectified Skew Orthomorphic), Alaska panhandle (State Plane zone 1)
+//! or Switzerland, and for imagery along satellite ground tracks
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Oblique_Mercator_projection).
+//!
+//! This implementation follows [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395) and
+//! EPSG Guidance Note 7-2, with both variants of the projection: variant A (EPSG method 9812),
+//! in which false easting and northing are given at the natural origin of the projection,
+//! and variant B (EPSG method 9815), in which they are given at the projection centre.
+//! The initial line can be defined either by its azimuth at the projection centre
+//! or by two points lying on it.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Cylindrical (oblique).
+//! - Conformal.
+//! - Two meridians 180° apart are straight lines.
+//! - Other meridians and parallels are complex curves.
+//! - Scale on ellipsoid is true along a chosen central line (exact for sphere).
+//! - Scale becomes infinite on sphere 90° from the central line.
+//! - Used for plane coordinates in some national grids and for satellite imagery.
+//! - Developed 1900-50 by Rosenmund, Laborde and Hotine.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::lambert_conformal_conic::{phi_for_inverse, t};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Tolerance of the latitude of the poles and of the special cases in the projection formulas.
+const TOLERANCE: f64 = 1e-10;
+
+/// Tolerance of the longitude for which the cylinder coordinate is computed from the longitude directly.
+const LON_TOLERANCE: f64 = 1e-7;
+
+/// Variant of the projection, which determines where false easting and northing are applied.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum HotineVariant {
+    /// False easting and northing are given at the natural origin of the projection,
+    /// where the initial line crosses the Equator of the aposphere (EPSG method 9812,
+    /// equivalent to `+no_off` in Proj).
+    A,
+
+    /// False easting and northing are given at the projection centre (EPSG method 9815).
+    #[default]
+    B,
+}
+
+/// Main projection struct that is constructed from [`HotineObliqueMercatorBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct HotineObliqueMercator {
+    lon_0: f64,
+    big_a: f64,
+    big_b: f64,
+    big_e: f64,
+    sin_gamma_0: f64,
+    cos_gamma_0: f64,
+    sin_gamma_c: f64,
+    cos_gamma_c: f64,
+    u_0: f64,
+    v_pole_n: f64,
+    v_pole_s: f64,
+    false_easting: f64,
+    false_northing: f64,
+    ellps: Ellipsoid,
+}
+
+impl HotineObliqueMercator {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> HotineObliqueMercatorBuilder {
+        HotineObliqueMercatorBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`HotineObliqueMercator`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct HotineObliqueMercatorBuilder {
+    center_lon: Option<f64>,
+    center_lat: Option<f64>,
+    azimuth: Option<f64>,
+    rectified_grid_angle: Option<f64>,
+    points: Option<(f64, f64, f64, f64)>,
+    scale_factor: f64,
+    false_easting: f64,
+    false_northing: f64,
+    variant: HotineVariant,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for HotineObliqueMercatorBuilder {
+    fn default() -> Self {
+        Self {
+            center_lon: None,
+            center_lat: None,
+            azimuth: None,
+            rectified_grid_angle: None,
+            points: None,
+            scale_factor: 1.0,
+            false_easting: 0.0,
+            false_northing: 0.0,
+            variant: HotineVariant::B,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl HotineObliqueMercatorBuilder {
+    /// *(required)* Sets longitude and latitude of the projection centre.
+    /// When the initial line is defined by [`two_points`](HotineObliqueMercatorBuilder::two_points)
+    /// only the latitude is used, as the initial line is then fixed by the points.
+    pub const fn center(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.center_lon = Some(lon);
+        self.center_lat = Some(lat);
+        self
+    }
+
+    /// *(required, alternative with [`two_points`](HotineObliqueMercatorBuilder::two_points))*
+    /// Sets azimuth (in degrees east of north) of the initial line at the projection centre.
+    pub const fn azimuth(&mut self, azimuth: f64) -> &mut Self {
+        self.azimuth = Some(azimuth);
+        self.points = None;
+        self
+    }
+
+    /// *(optional)* Sets angle (in degrees) from the rectified grid to the skew (oblique) grid,
+    /// defaults to the azimuth of the initial line. Ignored when the initial line is defined
+    /// by [`two_points`](HotineObliqueMercatorBuilder::two_points).
+    pub const fn rectified_grid_angle(&mut self, gamma: f64) -> &mut Self {
+        self.rectified_grid_angle = Some(gamma);
+        self
+    }
+
+    /// *(required, alternative with [`azimuth`](HotineObliqueMercatorBuilder::azimuth))*
+    /// Sets longitudes and latitudes of two points defining the initial line.
+    pub const fn two_points(
+        &mut self,
+        lon_1: f64,
+        lat_1: f64,
+        lon_2: f64,
+        lat_2: f64,
+    ) -> &mut Self {
+        self.points = Some((lon_1, lat_1, lon_2, lat_2));
+        self.azimuth = None;
+        self
+    }
+
+    /// *(optional)* Sets scale factor on the initial line, defaults to `1.0`.
+    pub const fn scale_factor(&mut self, k_0: f64) -> &mut Self {
+        self.scale_factor = k_0;
+        self
+    }
+
+    /// *(optional)* Sets false easting and false northing (in meters) added to projected coordinates,
+    /// defaults to `(0.0, 0.0)`. Depending on the [`variant`](HotineObliqueMercatorBuilder::variant)
+    /// these are the coordinates of the natural origin or of the projection centre.
+    pub const fn false_origin(&mut self, easting: f64, northing: f64) -> &mut Self {
+        self.false_easting = easting;
+        self.false_northing = northing;
+        self
+    }
+
+    /// *(optional)* Sets variant of the projection, defaults to [`HotineVariant::B`].
+    pub const fn variant(&mut self, variant: HotineVariant) -> &mut Self {
+        self.variant = variant;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// HOM projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - one or more longitudes are not within -180..180 range.
+    /// - one or more latitudes are not within -90..90 range.
+    /// - azimuth or rectified grid angle is not within -360..360 range.
+    /// - one or more arguments are not finite.
+    /// - latitude of the projection centre or of one of the points is at the pole.
+    /// - latitudes of the two points are equal or the first of them is on the Equator.
+    /// - scale factor is not positive.
+    pub fn initialize_projection(&self) -> Result<HotineObliqueMercator, ProjectionError> {
+        let center_lat = unpack_required_parameter!(self, center_lat);
+        let scale_factor = self.scale_factor;
+        let false_easting = self.false_easting;
+        let false_northing = self.false_northing;
+        let ellps = self.ellipsoid;
+        ensure_finite!(center_lat, scale_factor, false_easting, false_northing);
+
+        ensure_within_range!(center_lat, -90.0..90.0);
+
+        if approx_eq!(f64, center_lat.abs(), 90.0) {
+            return Err(ProjectionError::IncorrectParams(
+                "latitude of the projection centre must not be at the pole",
+            ));
+        }
+
+        if scale_factor <= 0.0 {
+            return Err(ProjectionError::IncorrectParams(
+                "scale factor must be positive",
+            ));
+        }
+
+        let phi_c = center_lat.to_radians();
+        let (big_a, big_b, big_e, d, f) = aposphere(phi_c, scale_factor, ellps);
+
+        let (lambda_0, gamma_0, gamma_c, alpha_c) = if let Some(points) = self.points {
+            let (lon_1, lat_1, lon_2, lat_2) = points;
+            ensure_finite!(lon_1, lat_1, lon_2, lat_2);
+            ensure_within_range!(lon_1, -180.0..180.0);
+            ensure_within_range!(lon_2, -180.0..180.0);
+            ensure_within_range!(lat_1, -90.0..90.0);
+            ensure_within_range!(lat_2, -90.0..90.0);
+
+            if approx_eq!(f64, lat_1.abs(), 90.0) || approx_eq!(f64, lat_2.abs(), 90.0) {
+                return Err(ProjectionError::IncorrectParams(
+                    "points defining the initial line must not be at the pole",
+                ));
+            }
+
+            if approx_eq!(f64, lat_1, lat_2) {
+                return Err(ProjectionError::IncorrectParams(
+                    "points defining the initial line must have different latitudes",
+                ));
+            }
+
+            if approx_eq!(f64, lat_1, 0.0) {
+                return Err(ProjectionError::IncorrectParams(
+                    "first point defining the initial line must not be on the Equator",
+                ));
+            }
+
+            two_point_line(
+                (lon_1.to_radians(), lat_1.to_radians()),
+                (lon_2.to_radians(), lat_2.to_radians()),
+                (big_b, big_e, d),
+                ellps,
+            )
+        } else {
+            let center_lon = unpack_required_parameter!(self, center_lon);
+            let azimuth = unpack_required_parameter!(self, azimuth);
+            let rectified_grid_angle = self.rectified_grid_angle.unwrap_or(azimuth);
+            ensure_finite!(center_lon, azimuth, rectified_grid_angle);
+
+            ensure_within_range!(center_lon, -180.0..180.0);
+            ensure_within_range!(azimuth, -360.0..360.0);
+            ensure_within_range!(rectified_grid_angle, -360.0..360.0);
+
+            let alpha_c = azimuth.to_radians();
+            let gamma_0 = (alpha_c.sin() / d).clamp(-1.0, 1.0).asin();
+
+            let lambda_0 = center_lon.to_radians()
+                - (0.5 * (f - 1.0 / f) * gamma_0.tan())
+                    .clamp(-1.0, 1.0)
+                    .asin()
+                    / big_b;
+
+            (
+                lambda_0,
+                gamma_0,
+                rectified_grid_angle.to_radians(),
+                alpha_c,
+            )
+        };
+
+        let u_0 = match self.variant {
+            HotineVariant::A => 0.0,
+            HotineVariant::B => (big_a / big_b
+                * (d.mul_add(d, -1.0).sqrt() / alpha_c.cos()).atan())
+            .abs()
+            .copysign(phi_c),
+        };
+
+        let v_pole_n = big_a / big_b * (FRAC_PI_4 - 0.5 * gamma_0).tan().ln();
+        let v_pole_s = big_a / big_b * (FRAC_PI_4 + 0.5 * gamma_0).tan().ln();
+
+        Ok(HotineObliqueMercator {
+            lon_0: adjust_lon(lambda_0.to_degrees()),
+            big_a,
+            big_b,
+            big_e,
+            sin_gamma_0: gamma_0.sin(),
+            cos_gamma_0: gamma_0.cos(),
+            sin_gamma_c: gamma_c.sin(),
+            cos_gamma_c: gamma_c.cos(),
+            u_0,
+            v_pole_n,
+            v_pole_s,
+            false_easting,
+            false_northing,
+            ellps,
+        })
+    }
+}
+
+impl Projection for HotineObliqueMercator {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+        let a_b = self.big_a / self.big_b;
+
+        // Coordinates (u, v) on the skew grid, with u along the initial line
+        let (u, v) = if (phi.abs() - FRAC_PI_2).abs() > TOLERANCE {
+            let big_q = self.big_e / t(phi, self.ellps).powf(self.big_b);
+            let big_s = 0.5 * (big_q - 1.0 / big_q);
+            let big_t = 0.5 * (big_q + 1.0 / big_q);
+            let (big_v, cos_b_lambda) = (self.big_b * lambda).sin_cos();
+            let big_u = big_s.mul_add(self.sin_gamma_0, -(big_v * self.cos_gamma_0)) / big_t;
+
+            if (big_u.abs() - 1.0).abs() < TOLERANCE {
+                return (f64::NAN, f64::NAN);
+            }
+
+            let v = 0.5 * a_b * ((1.0 - big_u) / (1.0 + big_u)).ln();
+            let u = if cos_b_lambda.abs() < LON_TOLERANCE {
+                self.big_a * lambda
+            } else {
+                a_b * big_s
+                    .mul_add(self.cos_gamma_0, big_v * self.sin_gamma_0)
+                    .atan2(cos_b_lambda)
+            };
+
+            (u, v)
+        } else if phi > 0.0 {
+            (a_b * phi, self.v_pole_n)
+        } else {
+            (a_b * phi, self.v_pole_s)
+        };
+
+        let u = u - self.u_0;
+
+        let x = v.mul_add(self.cos_gamma_c, u * self.sin_gamma_c);
+        let y = u.mul_add(self.cos_gamma_c, -(v * self.sin_gamma_c));
+
+        (
+            self.ellps.A.mul_add(x, self.false_easting),
+            self.ellps.A.mul_add(y, self.false_northing),
+        )
+    }
+
+    #[inline]
+    #[allow(clippy::many_single_char_names)]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = (x - self.false_easting) / self.ellps.A;
+        let y = (y - self.false_northing) / self.ellps.A;
+        let b_a = self.big_b / self.big_a;
+
+        let v = x.mul_add(self.cos_gamma_c, -(y * self.sin_gamma_c));
+        let u = y.mul_add(self.cos_gamma_c, x * self.sin_gamma_c) + self.u_0;
+
+        let big_q = (-b_a * v).exp();
+        let big_s = 0.5 * (big_q - 1.0 / big_q);
+        let big_t = 0.5 * (big_q + 1.0 / big_q);
+        let big_v = (b_a * u).sin();
+        let big_u = big_v.mul_add(self.cos_gamma_0, big_s * self.sin_gamma_0) / big_t;
+
+        let (lambda, phi) = if (big_u.abs() - 1.0).abs() < TOLERANCE {
+            (0.0, FRAC_PI_2.copysign(big_u))
+        } else {
+            let t = (self.big_e / ((1.0 + big_u) / (1.0 - big_u)).sqrt()).powf(1.0 / self.big_b);
+            let phi = phi_for_inverse(t, self.ellps);
+            let lambda = -big_s
+                .mul_add(self.cos_gamma_0, -(big_v * self.sin_gamma_0))
+                .atan2((b_a * u).cos())
+                / self.big_b;
+
+            (lambda, phi)
+        };
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Computes constants A, B, E, D and F of the aposphere
+/// from EPSG Guidance Note 7-2, section 3.2.4.
+fn aposphere(phi_c: f64, k_0: f64, ellps: Ellipsoid) -> (f64, f64, f64, f64, f64) {
+    let e2 = ellps.E * ellps.E;
+    let sqrt_one_e2 = (1.0 - e2).sqrt();
+
+    if phi_c.abs() <= TOLERANCE {
+        return (k_0, 1.0 / sqrt_one_e2, 1.0, 1.0, 1.0);
+    }
+
+    let (sin_phi_c, cos_phi_c) = phi_c.sin_cos();
+    let con = (e2 * sin_phi_c).mul_add(-sin_phi_c, 1.0);
+    let big_b = (e2 * cos_phi_c.powi(4) / (1.0 - e2) + 1.0).sqrt();
+    let big_a = big_b * k_0 * sqrt_one_e2 / con;
+    let d = big_b * sqrt_one_e2 / (cos_phi_c * con.sqrt());
+    let f = d + d.mul_add(d, -1.0).max(0.0).sqrt().copysign(phi_c);
+    let big_e = f * t(phi_c, ellps).powf(big_b);
+
+    (big_a, big_b, big_e, d, f)
+}
+
+/// Computes longitude of the natural origin, azimuth of the initial line at the natural origin,
+/// rectified grid angle and azimuth at the projection centre from two points on the initial line,
+/// as in Snyder (1987) formulas (9-21) to (9-24). The rectified grid angle is then equal
+/// to the azimuth at the projection centre.
+fn two_point_line(
+    (lambda_1, phi_1): (f64, f64),
+    (lambda_2, phi_2): (f64, f64),
+    (big_b, big_e, d): (f64, f64, f64),
+    ellps: Ellipsoid,
+) -> (f64, f64, f64, f64) {
+    let big_h = t(phi_1, ellps).powf(big_b);
+    let big_l = t(phi_2, ellps).powf(big_b);
+    let f = big_e / big_h;
+    let p = (big_l - big_h) / (big_l + big_h);
+    let j = big_e.mul_add(big_e, -(big_l * big_h)) / big_e.mul_add(big_e, big_l * big_h);
+
+    // Longitudes of the points must not be further than 180° apart
+    let lambda_2 = match lambda_1 - lambda_2 {
+        diff if diff < -PI => lambda_2 - 2.0 * PI,
+        diff if diff > PI => lambda_2 + 2.0 * PI,
+        _ => lambda_2,
+    };
+
+    let lambda_0 = 0.5f64.mul_add(
+        lambda_1 + lambda_2,
+        -(j * (0.5 * big_b * (lambda_1 - lambda_2)).tan() / p).atan() / big_b,
+    );
+    let lambda_0 = adjust_lon(lambda_0.to_degrees()).to_radians();
+
+    let gamma_0 = (2.0
+        * (big_b * adjust_lon((lambda_1 - lambda_0).to_degrees()).to_radians()).sin()
+        / (f - 1.0 / f))
+        .atan();
+    let alpha_c = (d * gamma_0.sin()).clamp(-1.0, 1.0).asin();
+
+    (lambda_0, gamma_0, alpha_c, alpha_c)
+}
@@ -0,0 +1,307 @@
+//! Interrupted projections split the map of the world into lobes, each of which is a separate part
+//! of a base (usually pseudocylindrical) projection with its own central meridian. Interruptions
+//! reduce distortion of land or ocean areas, at the cost of gaps between the lobes
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Interruption_(map_projection)).
+//!
+//! [`Interrupted`] wraps any [`Projection`] with central meridian at 0°. Each [`Lobe`] covers
+//! a range of longitudes in one hemisphere and is shifted along the Equator, so that its central
+//! meridian is placed where the base projection puts that longitude. Points in the gaps between
+//! lobes return [`ProjectionError::PointInInterruption`] from checked
+//! [`project`](Projection::project) and [`inverse_project`](Projection::inverse_project).
+//!
+//! For the Interrupted Goode Homolosine, see [`GoodeHomolosine::interrupted`](crate::projections::GoodeHomolosine::interrupted).
+
+use crate::Projection;
+use crate::errors::{ProjectionError, ensure_finite, unpack_required_parameter};
+use crate::projections::Hemisphere;
+use crate::projections::oblique_lon_lat::adjust_lon;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Tolerance of the longitude (in degrees) for which inverse projected point
+/// is still considered to be within the lobe.
+const LON_TOLERANCE: f64 = 1e-9;
+
+/// Tolerance of map coordinates (in meters) for which inverse projected point,
+/// when projected again, is considered to be the same point.
+const MAP_TOLERANCE: f64 = 1e-3;
+
+/// Single lobe of an [`Interrupted`] projection.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Lobe {
+    /// Longitude of the western boundary of the lobe.
+    pub west_lon: f64,
+
+    /// Longitude of the eastern boundary of the lobe.
+    pub east_lon: f64,
+
+    /// Longitude of the central meridian of the lobe.
+    pub central_lon: f64,
+
+    /// Hemisphere covered by the lobe. Points on the Equator belong to northern lobes.
+    pub hemisphere: Hemisphere,
+}
+
+impl Lobe {
+    /// Creates a lobe covering longitudes from `west_lon` to `east_lon` in given hemisphere,
+    /// with central meridian at `central_lon`.
+    #[must_use]
+    pub const fn new(
+        west_lon: f64,
+        east_lon: f64,
+        central_lon: f64,
+        hemisphere: Hemisphere,
+    ) -> Self {
+        Self {
+            west_lon,
+            east_lon,
+            central_lon,
+            hemisphere,
+        }
+    }
+
+    fn contains_lon(&self, lon: f64, tolerance: f64) -> bool {
+        lon >= self.west_lon - tolerance && lon <= self.east_lon + tolerance
+    }
+}
+
+/// Main projection struct that is constructed from [`InterruptedBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Interrupted<P: Projection, const N: usize> {
+    base: P,
+    lobes: [Lobe; N],
+    x_offsets: [f64; N],
+}
+
+impl<P: Projection, const N: usize> Interrupted<P, N> {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> InterruptedBuilder<P, N> {
+        InterruptedBuilder::default()
+    }
+
+    /// Finds the lobe containing given point, points on lobe boundaries belong to the western lobe.
+    fn lobe_index(&self, lon: f64, lat: f64) -> Option<usize> {
+        let hemisphere = hemisphere_of(lat);
+
+        self.lobes
+            .iter()
+            .position(|lobe| lobe.hemisphere == hemisphere && lobe.contains_lon(lon, 0.0))
+    }
+
+    /// Inverse projects the point with every lobe of the hemisphere, until the result is within the lobe.
+    ///
+    /// Base projection wraps longitudes it returns, so points outside of its map can be inverse projected
+    /// into the lobe. Therefore the result is projected again and accepted only if it is the same point.
+    fn inverse(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        let hemisphere = hemisphere_of(y);
+        let mut on_map = false;
+
+        for (lobe, x_0) in self.lobes.iter().zip(self.x_offsets) {
+            if lobe.hemisphere != hemisphere {
+                continue;
+            }
+
+            let (lon, lat) = self.base.inverse_project_unchecked(x - x_0, y);
+
+            if !lon.is_finite() || !lat.is_finite() {
+                continue;
+            }
+
+            let (tst_x, tst_y) = self.base.project_unchecked(lon, lat);
+
+            if (tst_x - (x - x_0)).abs() > MAP_TOLERANCE || (tst_y - y).abs() > MAP_TOLERANCE {
+                continue;
+            }
+
+            on_map = true;
+            let lon = lon + lobe.central_lon;
+
+            if lobe.contains_lon(lon, LON_TOLERANCE) {
+                return Ok((lon.clamp(lobe.west_lon, lobe.east_lon), lat));
+            }
+        }
+
+        if on_map {
+            Err(ProjectionError::PointInInterruption(x, y))
+        } else {
+            Err(ProjectionError::InverseProjectionImpossible(x, y))
+        }
+    }
+}
+
+/// Builder struct which allows to construct [`Interrupted`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct InterruptedBuilder<P: Projection, const N: usize> {
+    base: Option<P>,
+    lobes: Option<[Lobe; N]>,
+}
+
+impl<P: Projection, const N: usize> Default for InterruptedBuilder<P, N> {
+    fn default() -> Self {
+        Self {
+            base: None,
+            lobes: None,
+        }
+    }
+}
+
+impl<P: Projection, const N: usize> InterruptedBuilder<P, N> {
+    /// *(required)* Sets the base projection, which must have central meridian at 0°.
+    pub const fn base_projection(&mut self, base: P) -> &mut Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// *(required)* Sets lobes of the projection. Lobes in the same hemisphere cannot overlap,
+    /// and longitudes which are not covered by any lobe are treated as interruptions.
+    pub const fn lobes(&mut self, lobes: [Lobe; N]) -> &mut Self {
+        self.lobes = Some(lobes);
+        self
+    }
+
+    /// Interrupted projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - there are no lobes.
+    /// - lobe boundaries are not within -180..=180 range or western boundary is not west of the eastern one.
+    /// - central meridian of a lobe is outside of the lobe or more than 180° from its boundary.
+    /// - lobes in the same hemisphere overlap.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Interrupted<P, N>, ProjectionError> {
+        let base = unpack_required_parameter!(self, base);
+        let lobes = unpack_required_parameter!(self, lobes);
+
+        if N == 0 {
+            return Err(ProjectionError::IncorrectParams(
+                "at least one lobe is required",
+            ));
+        }
+
+        for lobe in &lobes {
+            ensure_valid_lobe(lobe)?;
+        }
+
+        for (i, lobe) in lobes.iter().enumerate() {
+            let overlaps = lobes[i + 1..].iter().any(|other| {
+                other.hemisphere == lobe.hemisphere
+                    && other.west_lon < lobe.east_lon
+                    && lobe.west_lon < other.east_lon
+            });
+
+            if overlaps {
+                return Err(ProjectionError::IncorrectParams(
+                    "lobes in the same hemisphere cannot overlap",
+                ));
+            }
+        }
+
+        Ok(Interrupted {
+            base,
+            lobes,
+            x_offsets: lobes.map(|lobe| base.project_unchecked(lobe.central_lon, 0.0).0),
+        })
+    }
+}
+
+impl<P: Projection, const N: usize> Projection for Interrupted<P, N> {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::PointInInterruption`] when the point is not within any lobe.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        if self.lobe_index(adjust_lon(lon), lat).is_none() {
+            return Err(ProjectionError::PointInInterruption(lon, lat));
+        }
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::PointInInterruption`] when the point is in the gap between lobes.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        self.inverse(x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lon = adjust_lon(lon);
+
+        let Some(i) = self.lobe_index(lon, lat) else {
+            return (f64::NAN, f64::NAN);
+        };
+
+        let (x, y) = self
+            .base
+            .project_unchecked(lon - self.lobes[i].central_lon, lat);
+
+        (x + self.x_offsets[i], y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        self.inverse(x, y).unwrap_or((f64::NAN, f64::NAN))
+    }
+}
+
+/// Hemisphere of the latitude or northing, the Equator belongs to the northern hemisphere.
+fn hemisphere_of(lat: f64) -> Hemisphere {
+    if lat >= 0.0 {
+        Hemisphere::North
+    } else {
+        Hemisphere::South
+    }
+}
+
+/// Checks boundaries and central meridian of the lobe.
+fn ensure_valid_lobe(lobe: &Lobe) -> Result<(), ProjectionError> {
+    let Lobe {
+        west_lon,
+        east_lon,
+        central_lon,
+        ..
+    } = *lobe;
+    ensure_finite!(west_lon, east_lon, central_lon);
+
+    if west_lon < -180.0 || east_lon > 180.0 || west_lon >= east_lon {
+        return Err(ProjectionError::IncorrectParams(
+            "lobe boundaries must be within -180..=180 range with western boundary west of the eastern one",
+        ));
+    }
+
+    if !lobe.contains_lon(central_lon, 0.0)
+        || central_lon - west_lon > 180.0
+        || east_lon - central_lon > 180.0
+    {
+        return Err(ProjectionError::IncorrectParams(
+            "central meridian of lobe must be within the lobe and at most 180° from its boundaries",
+        ));
+    }
+
+    Ok(())
+}
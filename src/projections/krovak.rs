@@ -0,0 +1,331 @@
+//! The Krovak projection is an oblique conformal conic projection on the Gaussian conformal sphere,
+//! used in the Czech Republic and Slovakia for the S-JTSK coordinate system (EPSG:5514)
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Krovak_projection).
+//!
+//! This implementation follows EPSG Guidance Note 7-2, with both the original Krovak projection
+//! (EPSG method 9819), in which coordinates are given as westing and southing, and its
+//! north-orientated variant (EPSG method 1041), in which coordinates are given as easting and northing.
+//! All parameters default to the values used by S-JTSK on the Bessel 1841 ellipsoid,
+//! with longitudes relative to Greenwich.
+//!
+//! The original projection returns `(westing, southing)`, both positive in the Czech Republic and Slovakia.
+//! These are respectively the second (`Y`) and the first (`X`) axis of EPSG:5513, which lists southing first,
+//! and the same as the output of `krovak` projection with `+czech` option in `Proj`.
+//! The north-orientated variant returns `(easting, northing)`, equal to `(-westing, -southing)`.
+//! These are the first (`X`) and the second (`Y`) axis of EPSG:5514, and the same as the output
+//! of `krovak` projection without `+czech` option in `Proj`.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+use std::f64::consts::FRAC_PI_4;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Longitude of origin of S-JTSK (24°50′ east of Greenwich, 42°30′ east of Ferro).
+const SJTSK_LON: f64 = 24.833_333_333_333_332;
+
+/// Latitude of projection centre of S-JTSK (49°30′ N).
+const SJTSK_LAT: f64 = 49.5;
+
+/// Co-latitude of cone axis of S-JTSK (30°17′17.30311″).
+const SJTSK_COLAT: f64 = 30.288_139_752_777_78;
+
+/// Latitude of pseudo standard parallel of S-JTSK (78°30′ N).
+const SJTSK_PSEUDO_LAT: f64 = 78.5;
+
+/// Scale factor on pseudo standard parallel of S-JTSK.
+const SJTSK_SCALE: f64 = 0.9999;
+
+/// Tolerance of the latitude iteration in the inverse projection.
+const TOLERANCE: f64 = 1e-15;
+
+/// Maximum number of iterations of the latitude in the inverse projection.
+const MAX_ITERATIONS: usize = 15;
+
+/// Main projection struct that is constructed from [`KrovakBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Krovak {
+    lon_0: f64,
+    big_b: f64,
+    t_0: f64,
+    n: f64,
+    r_0_tan_p: f64,
+    sin_alpha_c: f64,
+    cos_alpha_c: f64,
+    false_easting: f64,
+    false_northing: f64,
+    north_orientated: bool,
+    ellps: Ellipsoid,
+}
+
+impl Krovak {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> KrovakBuilder {
+        KrovakBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Krovak`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct KrovakBuilder {
+    center_lon: f64,
+    center_lat: f64,
+    cone_axis_colatitude: f64,
+    pseudo_standard_parallel: f64,
+    scale_factor: f64,
+    false_easting: f64,
+    false_northing: f64,
+    north_orientated: bool,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for KrovakBuilder {
+    fn default() -> Self {
+        Self {
+            center_lon: SJTSK_LON,
+            center_lat: SJTSK_LAT,
+            cone_axis_colatitude: SJTSK_COLAT,
+            pseudo_standard_parallel: SJTSK_PSEUDO_LAT,
+            scale_factor: SJTSK_SCALE,
+            false_easting: 0.0,
+            false_northing: 0.0,
+            north_orientated: false,
+            ellipsoid: Ellipsoid::BESSEL1841,
+        }
+    }
+}
+
+impl KrovakBuilder {
+    /// *(optional)* Sets longitude of origin and latitude of the projection centre,
+    /// defaults to `(24.833333, 49.5)` (24°50′ E, 49°30′ N).
+    pub const fn center(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.center_lon = lon;
+        self.center_lat = lat;
+        self
+    }
+
+    /// *(optional)* Sets co-latitude of the cone axis (in degrees) on the conformal sphere,
+    /// defaults to `30.288139` (30°17′17.30311″).
+    pub const fn cone_axis_colatitude(&mut self, alpha_c: f64) -> &mut Self {
+        self.cone_axis_colatitude = alpha_c;
+        self
+    }
+
+    /// *(optional)* Sets latitude of the pseudo standard parallel on the conformal sphere,
+    /// defaults to `78.5` (78°30′ N).
+    pub const fn pseudo_standard_parallel(&mut self, lat: f64) -> &mut Self {
+        self.pseudo_standard_parallel = lat;
+        self
+    }
+
+    /// *(optional)* Sets scale factor on the pseudo standard parallel, defaults to `0.9999`.
+    pub const fn scale_factor(&mut self, k_p: f64) -> &mut Self {
+        self.scale_factor = k_p;
+        self
+    }
+
+    /// *(optional)* Sets false easting and false northing (in meters), defaults to `(0.0, 0.0)`.
+    /// As in EPSG definitions, they are added to the westing and southing respectively,
+    /// before the axes are reversed in the north-orientated variant.
+    pub const fn false_origin(&mut self, easting: f64, northing: f64) -> &mut Self {
+        self.false_easting = easting;
+        self.false_northing = northing;
+        self
+    }
+
+    /// *(optional)* Sets whether the north-orientated variant (EPSG method 1041) is used,
+    /// which returns `(easting, northing)` instead of `(westing, southing)`, defaults to `false`.
+    pub const fn north_orientated(&mut self, north_orientated: bool) -> &mut Self {
+        self.north_orientated = north_orientated;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`BESSEL1841`](Ellipsoid::BESSEL1841).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Krovak projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - longitude of origin is not within -180..180 range.
+    /// - latitude of the projection centre or of the pseudo standard parallel is not within -90..90 range.
+    /// - co-latitude of the cone axis is not within 0..90 range.
+    /// - one or more arguments are not finite.
+    /// - pseudo standard parallel is on the Equator.
+    /// - scale factor is not positive.
+    pub fn initialize_projection(&self) -> Result<Krovak, ProjectionError> {
+        let center_lon = self.center_lon;
+        let center_lat = self.center_lat;
+        let cone_axis_colatitude = self.cone_axis_colatitude;
+        let pseudo_standard_parallel = self.pseudo_standard_parallel;
+        let scale_factor = self.scale_factor;
+        let false_easting = self.false_easting;
+        let false_northing = self.false_northing;
+        let ellps = self.ellipsoid;
+        ensure_finite!(
+            center_lon,
+            center_lat,
+            cone_axis_colatitude,
+            pseudo_standard_parallel,
+            scale_factor,
+            false_easting,
+            false_northing
+        );
+
+        ensure_within_range!(center_lon, -180.0..180.0);
+        ensure_within_range!(center_lat, -90.0..90.0);
+        ensure_within_range!(cone_axis_colatitude, 0.0..90.0);
+        ensure_within_range!(pseudo_standard_parallel, -90.0..90.0);
+
+        if approx_eq!(f64, pseudo_standard_parallel, 0.0) {
+            return Err(ProjectionError::IncorrectParams(
+                "pseudo standard parallel must not be on the Equator",
+            ));
+        }
+
+        if scale_factor <= 0.0 {
+            return Err(ProjectionError::IncorrectParams(
+                "scale factor must be positive",
+            ));
+        }
+
+        let phi_c = center_lat.to_radians();
+        let phi_p = pseudo_standard_parallel.to_radians();
+        let alpha_c = cone_axis_colatitude.to_radians();
+        let e = ellps.E;
+        let e2 = e.powi(2);
+
+        let big_a = ellps.A * (1.0 - e2).sqrt() / (1.0 - e2 * phi_c.sin().powi(2));
+        let big_b = (1.0 + e2 * phi_c.cos().powi(4) / (1.0 - e2)).sqrt();
+        let gamma_0 = (phi_c.sin() / big_b).asin();
+        let t_0 = (FRAC_PI_4 + gamma_0 / 2.0).tan() * conformal_factor(phi_c, e).powf(big_b)
+            / (FRAC_PI_4 + phi_c / 2.0).tan().powf(big_b);
+        let n = phi_p.sin();
+        let r_0 = scale_factor * big_a / phi_p.tan();
+
+        Ok(Krovak {
+            lon_0: center_lon,
+            big_b,
+            t_0,
+            n,
+            r_0_tan_p: r_0 * (FRAC_PI_4 + phi_p / 2.0).tan().powf(n),
+            sin_alpha_c: alpha_c.sin(),
+            cos_alpha_c: alpha_c.cos(),
+            false_easting,
+            false_northing,
+            north_orientated: self.north_orientated,
+            ellps,
+        })
+    }
+}
+
+impl Projection for Krovak {
+    #[inline]
+    #[allow(clippy::many_single_char_names)]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let e = self.ellps.E;
+
+        let u = 2.0
+            * ((self.t_0 * (phi / 2.0 + FRAC_PI_4).tan().powf(self.big_b)
+                / conformal_factor(phi, e).powf(self.big_b))
+            .atan()
+                - FRAC_PI_4);
+        let v = -self.big_b * adjust_lon(lon - self.lon_0).to_radians();
+
+        let t = (self.cos_alpha_c * u.sin() + self.sin_alpha_c * u.cos() * v.cos()).asin();
+        let cos_t = t.cos();
+
+        // At the apex of the cone both distance and angle vanish
+        let d = if cos_t > 0.0 {
+            (u.cos() * v.sin() / cos_t).asin()
+        } else {
+            0.0
+        };
+
+        let theta = self.n * d;
+        let r = self.r_0_tan_p / (t / 2.0 + FRAC_PI_4).tan().powf(self.n);
+
+        let westing = r * theta.sin() + self.false_easting;
+        let southing = r * theta.cos() + self.false_northing;
+
+        if self.north_orientated {
+            (-westing, -southing)
+        } else {
+            (westing, southing)
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::many_single_char_names)]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let (westing, southing) = if self.north_orientated {
+            (-x, -y)
+        } else {
+            (x, y)
+        };
+
+        let x_p = southing - self.false_northing;
+        let y_p = westing - self.false_easting;
+
+        let r = x_p.hypot(y_p);
+        let theta = y_p.atan2(x_p);
+
+        let d = theta / self.n;
+        let t = 2.0 * ((self.r_0_tan_p / r).powf(1.0 / self.n).atan() - FRAC_PI_4);
+
+        let u = (self.cos_alpha_c * t.sin() - self.sin_alpha_c * t.cos() * d.cos()).asin();
+        let v = (t.cos() * d.sin() / u.cos()).asin();
+
+        let e = self.ellps.E;
+        let base = (self.t_0.recip() * (u / 2.0 + FRAC_PI_4).tan()).powf(1.0 / self.big_b);
+
+        let mut phi = u;
+
+        for _ in 0..MAX_ITERATIONS {
+            let next = 2.0 * ((base * conformal_factor(phi, e)).atan() - FRAC_PI_4);
+            let converged = (next - phi).abs() < TOLERANCE;
+            phi = next;
+
+            if converged {
+                break;
+            }
+        }
+
+        (
+            adjust_lon(self.lon_0 - (v / self.big_b).to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Computes `((1 + e sin φ) / (1 - e sin φ))^(e/2)`, which relates latitudes
+/// on the ellipsoid and on the conformal sphere.
+fn conformal_factor(phi: f64, e: f64) -> f64 {
+    let e_sin_phi = e * phi.sin();
+
+    ((1.0 + e_sin_phi) / (1.0 - e_sin_phi)).powf(e / 2.0)
+}
@@ -0,0 +1,299 @@
+//! The Lambert azimuthal equal-area projection is a particular mapping from a sphere to a disk.
+//! It accurately represents area in all regions of the sphere, but it does not accurately represent angles
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Lambert_azimuthal_equal-area_projection).
+//!
+//! This implementation uses authalic latitude to handle the ellipsoid
+//! and supports polar, equatorial and oblique aspects. It is used, for example,
+//! by the pan-European statistical grids in ETRS89-LAEA (EPSG:3035).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Azimuthal.
+//! - Equal-Area.
+//! - All meridians in the polar aspect, the central meridian in other aspects, and the Equator in the equatorial aspect are straight lines.
+//! - The outer meridian of a hemisphere in the equatorial aspect (for the sphere) and the parallels in the polar aspect (sphere or ellipsoid) are circles.
+//! - All other meridians and parallels are complex curves.
+//! - Not a perspective projection.
+//! - Scale decreases radially as the distance increases from the center, the only point without distortion.
+//! - Directions from the center are true for the sphere and the polar ellipsoidal forms.
+//! - Point opposite the center is shown as a circle surrounding the map (for the sphere).
+//! - Used for maps of continents and hemispheres.
+//! - Presented by Lambert in 1772.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+use std::f64::consts::FRAC_PI_2;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Maximum number of iterations when computing latitude from authalic latitude.
+const MAX_ITERATIONS: usize = 15;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+enum Aspect {
+    Polar {
+        pole_sign: f64,
+    },
+    Oblique {
+        sin_beta_1: f64,
+        cos_beta_1: f64,
+        r_q: f64,
+        d: f64,
+    },
+}
+
+/// Main projection struct that is constructed from [`LambertAzimuthalEqualAreaBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct LambertAzimuthalEqualArea {
+    lambda_0: f64,
+    q_p: f64,
+    aspect: Aspect,
+    ellps: Ellipsoid,
+}
+
+impl LambertAzimuthalEqualArea {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> LambertAzimuthalEqualAreaBuilder {
+        LambertAzimuthalEqualAreaBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`LambertAzimuthalEqualArea`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct LambertAzimuthalEqualAreaBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for LambertAzimuthalEqualAreaBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl LambertAzimuthalEqualAreaBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    /// When reference latitude is a pole, the polar aspect of the projection is used.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// LAEA projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - reference longitude is not within -180..180 range.
+    /// - reference latitude is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<LambertAzimuthalEqualArea, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&ref_lat) {
+            return Err(ProjectionError::ParamOutOfRange("ref_lat", -90.0, 90.0));
+        }
+
+        let q_p = q(FRAC_PI_2, ellps);
+
+        let aspect = if approx_eq!(f64, ref_lat.abs(), 90.0) {
+            Aspect::Polar {
+                pole_sign: ref_lat.signum(),
+            }
+        } else {
+            let phi_1 = ref_lat.to_radians();
+            let beta_1 = (q(phi_1, ellps) / q_p).asin();
+            let r_q = ellps.A * (q_p / 2.0).sqrt();
+            let m_1 = phi_1.cos()
+                / (ellps.E * phi_1.sin())
+                    .mul_add(-(ellps.E * phi_1.sin()), 1.0)
+                    .sqrt();
+
+            Aspect::Oblique {
+                sin_beta_1: beta_1.sin(),
+                cos_beta_1: beta_1.cos(),
+                r_q,
+                d: ellps.A * m_1 / (r_q * beta_1.cos()),
+            }
+        };
+
+        Ok(LambertAzimuthalEqualArea {
+            lambda_0: ref_lon.to_radians(),
+            q_p,
+            aspect,
+            ellps,
+        })
+    }
+}
+
+impl Projection for LambertAzimuthalEqualArea {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = lon.to_radians() - self.lambda_0;
+        let q = q(lat.to_radians(), self.ellps);
+
+        match self.aspect {
+            Aspect::Polar { pole_sign } => {
+                // Formulas (24-23) to (24-25) from Snyder (1987)
+                let rho = self.ellps.A * pole_sign.mul_add(-q, self.q_p).sqrt();
+
+                let x = rho * lambda.sin();
+                let y = -pole_sign * rho * lambda.cos();
+
+                (x, y)
+            }
+            Aspect::Oblique {
+                sin_beta_1,
+                cos_beta_1,
+                r_q,
+                d,
+            } => {
+                // Formulas (24-11) to (24-13) from Snyder (1987)
+                let beta = (q / self.q_p).clamp(-1.0, 1.0).asin();
+
+                let b = r_q
+                    * (2.0
+                        / (cos_beta_1 * beta.cos())
+                            .mul_add(lambda.cos(), sin_beta_1.mul_add(beta.sin(), 1.0)))
+                    .sqrt();
+
+                let x = b * d * beta.cos() * lambda.sin();
+                let y = (b / d)
+                    * cos_beta_1.mul_add(beta.sin(), -(sin_beta_1 * beta.cos() * lambda.cos()));
+
+                (x, y)
+            }
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let (lambda, q) = match self.aspect {
+            Aspect::Polar { pole_sign } => {
+                // Formulas (24-30) and (24-31) from Snyder (1987)
+                let rho = x.hypot(y);
+                let q = pole_sign * (self.q_p - (rho / self.ellps.A).powi(2));
+
+                (x.atan2(-pole_sign * y), q)
+            }
+            Aspect::Oblique {
+                sin_beta_1,
+                cos_beta_1,
+                r_q,
+                d,
+            } => {
+                // Formulas (24-26) to (24-29) from Snyder (1987)
+                let rho = (x / d).hypot(d * y);
+
+                if approx_eq!(f64, rho, 0.0) {
+                    return (
+                        self.lambda_0.to_degrees(),
+                        phi_from_q(self.q_p * sin_beta_1, self.ellps).to_degrees(),
+                    );
+                }
+
+                let c_e = 2.0 * (rho / (2.0 * r_q)).asin();
+
+                let q = self.q_p
+                    * c_e
+                        .cos()
+                        .mul_add(sin_beta_1, d * y * c_e.sin() * cos_beta_1 / rho);
+                let lambda = (x * c_e.sin()).atan2(
+                    (d * rho * cos_beta_1)
+                        .mul_add(c_e.cos(), -(d * d * y * sin_beta_1 * c_e.sin())),
+                );
+
+                (lambda, q)
+            }
+        };
+
+        let lon = adjust_lon((lambda + self.lambda_0).to_degrees());
+        let lat = phi_from_q(q, self.ellps).to_degrees();
+
+        (lon, lat)
+    }
+}
+
+/// Computes q function used for authalic latitude, Snyder (1987) eq. (3-12).
+pub(crate) fn q(phi: f64, ellps: Ellipsoid) -> f64 {
+    let e = ellps.E;
+    let sin_phi = phi.sin();
+
+    (e * e).mul_add(-1.0, 1.0)
+        * (sin_phi / (e * sin_phi).mul_add(-(e * sin_phi), 1.0) + atanh_e(sin_phi, e))
+}
+
+/// Computes latitude for given value of q function with Newton's method,
+/// Snyder (1987) eq. (3-16).
+pub(crate) fn phi_from_q(q: f64, ellps: Ellipsoid) -> f64 {
+    let e = ellps.E;
+    let q_p = self::q(FRAC_PI_2, ellps);
+
+    if q.abs() >= q_p {
+        return FRAC_PI_2.copysign(q);
+    }
+
+    let mut phi = (q / 2.0).asin();
+
+    for _ in 0..MAX_ITERATIONS {
+        let sin_phi = phi.sin();
+        let one_minus_e2sin2 = (e * sin_phi).mul_add(-(e * sin_phi), 1.0);
+
+        let d_phi = one_minus_e2sin2.powi(2) / (2.0 * phi.cos())
+            * (q / (e * e).mul_add(-1.0, 1.0) - sin_phi / one_minus_e2sin2 - atanh_e(sin_phi, e));
+        phi += d_phi;
+
+        if d_phi.abs() < 1e-14 {
+            break;
+        }
+    }
+
+    phi
+}
+
+/// Computes `atanh(e * sin_phi) / e`, which is equal to `sin_phi` for the sphere.
+fn atanh_e(sin_phi: f64, e: f64) -> f64 {
+    if e < f64::EPSILON {
+        sin_phi
+    } else {
+        (e * sin_phi).atanh() / e
+    }
+}
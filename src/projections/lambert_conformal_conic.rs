@@ -123,14 +123,7 @@ impl LambertConformalConicBuilder {
 
         ensure_within_range!(ref_lon, -180.0..180.0);
         ensure_within_range!(ref_lat, -90.0..90.0);
-        ensure_within_range!(std_par_1, -90.0..90.0);
-        ensure_within_range!(std_par_2, -90.0..90.0);
-
-        if approx_eq!(f64, (std_par_1 + std_par_2).abs(), 0.0) {
-            return Err(ProjectionError::IncorrectParams(
-                "absolute value of sum of standard parallels must be positive",
-            ));
-        }
+        ensure_valid_parallels(std_par_1, std_par_2)?;
 
         let phi_0 = ref_lat.to_radians();
         let phi_1 = std_par_1.to_radians();
@@ -201,12 +194,30 @@ impl Projection for LambertConformalConic {
     }
 }
 
-fn t(phi: f64, ellps: Ellipsoid) -> f64 {
+/// Checks standard parallels of conic projection, which must be within -90..90 range
+/// and cannot be symmetric about the Equator.
+pub(crate) fn ensure_valid_parallels(
+    std_par_1: f64,
+    std_par_2: f64,
+) -> Result<(), ProjectionError> {
+    ensure_within_range!(std_par_1, -90.0..90.0);
+    ensure_within_range!(std_par_2, -90.0..90.0);
+
+    if approx_eq!(f64, (std_par_1 + std_par_2).abs(), 0.0) {
+        return Err(ProjectionError::IncorrectParams(
+            "absolute value of sum of standard parallels must be positive",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn t(phi: f64, ellps: Ellipsoid) -> f64 {
     (0.5f64.mul_add(-phi, FRAC_PI_4).tan())
         / ((ellps.E.mul_add(-phi.sin(), 1.0) / ellps.E.mul_add(phi.sin(), 1.0)).powf(ellps.E / 2.0))
 }
 
-fn m(phi: f64, ellps: Ellipsoid) -> f64 {
+pub(crate) fn m(phi: f64, ellps: Ellipsoid) -> f64 {
     phi.cos() / ellps.E.powi(2).mul_add(-(phi.sin()).powi(2), 1.0).sqrt()
 }
 
@@ -226,7 +237,7 @@ fn rho(big_f: f64, t: f64, n: f64, ellps: Ellipsoid) -> f64 {
 /// truncated infinite series is used with
 /// optimisations for reducing trigonometric
 /// functions calls.
-fn phi_for_inverse(t: f64, ellps: Ellipsoid) -> f64 {
+pub(crate) fn phi_for_inverse(t: f64, ellps: Ellipsoid) -> f64 {
     let chi = 2.0f64.mul_add(-t.atan(), FRAC_PI_2);
 
     let big_a = 13.0f64.mul_add(ellps.E.powi(8) / 360.0, 5.0f64.mul_add(ellps.E.powi(4) / 24.0, ellps.E.powi(2) / 2.0) + (ellps.E.powi(6) / 12.0));
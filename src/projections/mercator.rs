@@ -0,0 +1,251 @@
+//! The Mercator projection is a cylindrical map projection presented by Flemish geographer
+//! and cartographer Gerardus Mercator in 1569. It became the standard map projection for navigation
+//! because it is unique in representing north as up and south as down everywhere while preserving
+//! local directions and shapes [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Mercator_projection).
+//!
+//! This module provides the ellipsoidal [`Mercator`] projection, which can be defined
+//! with scale factor at the Equator (EPSG variant A) or with standard parallel (EPSG variant B),
+//! and the [`WebMercator`] projection (EPSG:3857) used by web map tiles.
+//!
+//! Both projections return [`ProjectionError::OutOfDomain`] from checked [`project`](Projection::project)
+//! when absolute value of latitude is bigger than [`MAX_LATITUDE`], instead of returning very big values.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Cylindrical.
+//! - Conformal.
+//! - Meridians are equally spaced straight lines.
+//! - Parallels are unequally spaced straight lines, closest near the Equator, cutting meridians at right angles.
+//! - Scale is true along the Equator, or along two parallels equidistant from the Equator.
+//! - Loxodromes (rhumb lines) are straight lines.
+//! - Not perspective.
+//! - Poles are at infinity; great distortion of area in polar regions.
+//! - Used for navigation.
+//! - Presented by Mercator in 1569.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::lambert_conformal_conic::{phi_for_inverse, t};
+use crate::projections::oblique_lon_lat::adjust_lon;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Latitude (in degrees) beyond which projection returns [`ProjectionError::OutOfDomain`].
+/// It is the latitude at which Web Mercator map becomes a square, `atan(sinh(π))`.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Main projection struct that is constructed from [`MercatorBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Mercator {
+    lambda_0: f64,
+    a_k_0: f64,
+    ellps: Ellipsoid,
+}
+
+impl Mercator {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> MercatorBuilder {
+        MercatorBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Mercator`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct MercatorBuilder {
+    central_lon: f64,
+    scale_factor: f64,
+    std_parallel: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for MercatorBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            scale_factor: 1.0,
+            std_parallel: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl MercatorBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional, alternative with [`standard_parallel`](MercatorBuilder::standard_parallel))* Sets scale factor
+    /// at the Equator (variant A), defaults to `1.0`.
+    pub const fn scale_factor(&mut self, k_0: f64) -> &mut Self {
+        self.scale_factor = k_0;
+        self.std_parallel = None;
+        self
+    }
+
+    /// *(optional, alternative with [`scale_factor`](MercatorBuilder::scale_factor))* Sets standard parallel
+    /// (latitude) along which scale is true (variant B). The same scale is kept along the parallel of opposite sign.
+    pub const fn standard_parallel(&mut self, std_parallel: f64) -> &mut Self {
+        self.std_parallel = Some(std_parallel);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Mercator projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - standard parallel is not within -90..90 range.
+    /// - one or more arguments are not finite.
+    /// - scale factor is not positive.
+    pub fn initialize_projection(&self) -> Result<Mercator, ProjectionError> {
+        let central_lon = self.central_lon;
+        let ellps = self.ellipsoid;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        let k_0 = if let Some(std_par) = self.std_parallel {
+            ensure_finite!(std_par);
+            ensure_within_range!(std_par, -90.0..90.0);
+
+            let phi_1 = std_par.to_radians();
+            phi_1.cos()
+                / (ellps.E * phi_1.sin())
+                    .mul_add(-(ellps.E * phi_1.sin()), 1.0)
+                    .sqrt()
+        } else {
+            let scale_factor = self.scale_factor;
+            ensure_finite!(scale_factor);
+
+            if scale_factor <= 0.0 {
+                return Err(ProjectionError::IncorrectParams(
+                    "scale factor must be positive",
+                ));
+            }
+
+            scale_factor
+        };
+
+        Ok(Mercator {
+            lambda_0: central_lon.to_radians(),
+            a_k_0: ellps.A * k_0,
+            ellps,
+        })
+    }
+}
+
+impl Projection for Mercator {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::OutOfDomain`] when absolute value of latitude is bigger than [`MAX_LATITUDE`].
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        ensure_within_mercator_domain(lon, lat)?;
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lambda_0.to_degrees()).to_radians();
+
+        let x = self.a_k_0 * lambda;
+        let y = -self.a_k_0 * t(phi, self.ellps).ln();
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let t = (-y / self.a_k_0).exp();
+
+        let lambda = (x / self.a_k_0) + self.lambda_0;
+        let phi = phi_for_inverse(t, self.ellps);
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
+
+/// Web Mercator (Pseudo-Mercator, EPSG:3857) projection used by web mapping applications.
+///
+/// It takes geographical coordinates on [`WGS84`](Ellipsoid::WGS84) ellipsoid, but projects them with
+/// spherical formulas using the semi-major axis as the radius. Therefore it is not conformal.
+/// As the projection is fully defined by EPSG it has no parameters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct WebMercator;
+
+impl Projection for WebMercator {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::OutOfDomain`] when absolute value of latitude is bigger than [`MAX_LATITUDE`].
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        ensure_within_mercator_domain(lon, lat)?;
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let x = Ellipsoid::WGS84.A * lon.to_radians();
+        let y = Ellipsoid::WGS84.A * lat.to_radians().tan().asinh();
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let lon = x / Ellipsoid::WGS84.A;
+        let lat = (y / Ellipsoid::WGS84.A).sinh().atan();
+
+        (lon.to_degrees(), lat.to_degrees())
+    }
+}
+
+fn ensure_within_mercator_domain(lon: f64, lat: f64) -> Result<(), ProjectionError> {
+    if lat.abs() > MAX_LATITUDE {
+        return Err(ProjectionError::OutOfDomain(lon, lat));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! The Miller cylindrical projection is a modified Mercator projection, proposed by Osborn Maitland Miller
+//! in 1942. The latitude is scaled by a factor of 4/5, projected according to Mercator, and then
+//! the result is multiplied by 5/4 to retain scale along the Equator. Unlike Mercator,
+//! the poles are shown as lines at finite distance
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Miller_cylindrical_projection).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Cylindrical.
+//! - Neither equal-area nor conformal.
+//! - Meridians and parallels are straight lines, intersecting at right angles.
+//! - Meridians are equidistant; parallels spaced farther apart away from Equator.
+//! - Poles shown as lines.
+//! - Used for world maps.
+//! - Avoids some scale exaggeration of Mercator.
+//! - Presented by Miller in 1942.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`MillerCylindricalBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct MillerCylindrical {
+    lon_0: f64,
+    r: f64,
+}
+
+impl MillerCylindrical {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> MillerCylindricalBuilder {
+        MillerCylindricalBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`MillerCylindrical`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct MillerCylindricalBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for MillerCylindricalBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl MillerCylindricalBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Miller Cylindrical projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<MillerCylindrical, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(MillerCylindrical {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for MillerCylindrical {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Spherical formulas from Snyder (1987)
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+
+        let x = self.r * lambda;
+        let y = self.r * 1.25 * 0.4f64.mul_add(phi, FRAC_PI_4).tan().ln();
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let lambda = x / self.r;
+        let phi = 2.5 * ((0.8 * y / self.r).exp().atan() - FRAC_PI_4);
+
+        // Points beyond the poles are outside of the map
+        if phi.abs() > FRAC_PI_2 {
+            return (f64::NAN, f64::NAN);
+        }
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
@@ -3,6 +3,11 @@
 //! It does not use Geodesic calculations so it is faster than the original projection, but significantly
 //! diverges from the AEQD projection at bigger scales.
 //!
+//! Snyder's series for Micronesia are accurate only within about 800 km from the center,
+//! so an optional accuracy limit can be set, beyond which checked projection functions
+//! return [`ProjectionError::OutOfDomain`]. The Guam projection (EPSG method 9831),
+//! a simpler approximation used for the island of Guam, is also available as a [variant](ModifiedAzimuthalEquidistantVariant).
+//!
 //! The azimuthal equidistant projection is an azimuthal map projection.
 //! It has the useful properties that all points on the map are at proportionally
 //! correct distances from the center point, and that all points on the map are at the
@@ -34,11 +39,29 @@ use crate::{
     Projection, ProjectionError,
     ellipsoids::Ellipsoid,
     errors::{ensure_finite, ensure_within_range, unpack_required_parameter},
+    projections::equidistant_conic::{meridian_distance, phi_from_meridian_distance},
+    projections::oblique_lon_lat::adjust_lon,
 };
 
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
+/// Series used to approximate the azimuthal equidistant projection.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum ModifiedAzimuthalEquidistantVariant {
+    /// Modified Azimuthal Equidistant (EPSG method 9832) for islands of Micronesia,
+    /// as described by Snyder (1987).
+    #[default]
+    Micronesia,
+
+    /// Guam Projection (EPSG method 9831), which is a simpler approximation
+    /// used for the island of Guam (equivalent to `aeqd +guam` in Proj).
+    Guam,
+}
+
+/// Number of iterations of the latitude in the inverse Guam projection, as specified by EPSG.
+const GUAM_ITERATIONS: usize = 3;
+
 /// Main projection struct that is constructed from [`ModifiedAzimuthalEquidistantBuilder`] and used for computations.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub struct ModifiedAzimuthalEquidistant {
@@ -46,6 +69,9 @@ pub struct ModifiedAzimuthalEquidistant {
     lat_0: f64,
     n_1: f64,
     g: f64,
+    big_m_0: f64,
+    variant: ModifiedAzimuthalEquidistantVariant,
+    accuracy_limit: Option<f64>,
     ellps: Ellipsoid,
 }
 
@@ -67,6 +93,8 @@ impl ModifiedAzimuthalEquidistant {
 pub struct ModifiedAzimuthalEquidistantBuilder {
     ref_lon: Option<f64>,
     ref_lat: Option<f64>,
+    variant: ModifiedAzimuthalEquidistantVariant,
+    accuracy_limit: Option<f64>,
     ellipsoid: Ellipsoid,
 }
 
@@ -75,6 +103,8 @@ impl Default for ModifiedAzimuthalEquidistantBuilder {
         Self {
             ref_lon: None,
             ref_lat: None,
+            variant: ModifiedAzimuthalEquidistantVariant::default(),
+            accuracy_limit: None,
             ellipsoid: Ellipsoid::WGS84,
         }
     }
@@ -88,6 +118,22 @@ impl ModifiedAzimuthalEquidistantBuilder {
         self
     }
 
+    /// *(optional)* Sets the series used by the projection,
+    /// defaults to [`Micronesia`](ModifiedAzimuthalEquidistantVariant::Micronesia).
+    pub const fn variant(&mut self, variant: ModifiedAzimuthalEquidistantVariant) -> &mut Self {
+        self.variant = variant;
+        self
+    }
+
+    /// *(optional)* Sets the maximum distance from the reference point (in meters) within which
+    /// the projection is considered accurate, by default it is not limited.
+    /// Checked projection functions return [`ProjectionError::OutOfDomain`] for points further away.
+    /// Series for Micronesia are accurate within about 800 km.
+    pub const fn accuracy_limit(&mut self, distance: f64) -> &mut Self {
+        self.accuracy_limit = Some(distance);
+        self
+    }
+
     /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
     pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
         self.ellipsoid = ellps;
@@ -109,6 +155,7 @@ impl ModifiedAzimuthalEquidistantBuilder {
     /// - one or more longitudes are not within -180..180 range.
     /// - one or more latitudes are not within -90..90 range.
     /// - one or more arguments are not finite.
+    /// - accuracy limit is not positive.
     pub fn initialize_projection(&self) -> Result<ModifiedAzimuthalEquidistant, ProjectionError> {
         let ref_lon = unpack_required_parameter!(self, ref_lon);
         let ref_lat = unpack_required_parameter!(self, ref_lat);
@@ -118,6 +165,16 @@ impl ModifiedAzimuthalEquidistantBuilder {
         ensure_within_range!(ref_lon, -180.0..180.0);
         ensure_within_range!(ref_lat, -90.0..90.0);
 
+        if let Some(accuracy_limit) = self.accuracy_limit {
+            ensure_finite!(accuracy_limit);
+
+            if accuracy_limit <= 0.0 {
+                return Err(ProjectionError::IncorrectParams(
+                    "accuracy limit must be positive",
+                ));
+            }
+        }
+
         let lon_0 = ref_lon.to_radians();
         let lat_0 = ref_lat.to_radians();
 
@@ -129,16 +186,22 @@ impl ModifiedAzimuthalEquidistantBuilder {
             lat_0,
             n_1,
             g,
+            big_m_0: meridian_distance(lat_0, ellps),
+            variant: self.variant,
+            accuracy_limit: self.accuracy_limit,
             ellps,
         })
     }
 }
 
-impl Projection for ModifiedAzimuthalEquidistant {
-    #[inline]
+impl ModifiedAzimuthalEquidistant {
+    /// Checks whether the point on the map is within the accuracy limit, if it is set.
+    fn within_accuracy_limit(&self, x: f64, y: f64) -> bool {
+        self.accuracy_limit.is_none_or(|limit| x.hypot(y) <= limit)
+    }
+
     #[allow(clippy::many_single_char_names)]
-    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
-    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+    fn micronesia_project(&self, lon: f64, lat: f64) -> (f64, f64) {
         let lon = lon.to_radians();
         let lat = lat.to_radians();
 
@@ -170,9 +233,7 @@ impl Projection for ModifiedAzimuthalEquidistant {
         (x, y)
     }
 
-    #[inline]
-    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
-    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+    fn micronesia_inverse_project(&self, x: f64, y: f64) -> (f64, f64) {
         let c = x.hypot(y);
         let az = x.atan2(y);
 
@@ -207,4 +268,93 @@ impl Projection for ModifiedAzimuthalEquidistant {
 
         (lon, lat)
     }
+
+    fn guam_project(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0.to_degrees()).to_radians();
+        let phi = lat.to_radians();
+
+        let nu = self.ellps.A / (1.0 - (self.ellps.E * phi.sin()).powi(2)).sqrt();
+
+        let x = nu * phi.cos() * lambda;
+        let y =
+            meridian_distance(phi, self.ellps) - self.big_m_0 + x.powi(2) * phi.tan() / (2.0 * nu);
+
+        (x, y)
+    }
+
+    fn guam_inverse_project(&self, x: f64, y: f64) -> (f64, f64) {
+        let mut phi = self.lat_0;
+        let mut t = 1.0;
+
+        for _ in 0..GUAM_ITERATIONS {
+            t = (1.0 - (self.ellps.E * phi.sin()).powi(2)).sqrt();
+            let big_m = self.big_m_0 + y - x.powi(2) * phi.tan() * t / (2.0 * self.ellps.A);
+            phi = phi_from_meridian_distance(big_m, self.ellps);
+        }
+
+        let lambda = x * t / (self.ellps.A * phi.cos());
+
+        (
+            adjust_lon((self.lon_0 + lambda).to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+impl Projection for ModifiedAzimuthalEquidistant {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::OutOfDomain`] when the point is beyond the accuracy limit.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            return Err(ProjectionError::ProjectionImpossible(lon, lat));
+        }
+
+        if !self.within_accuracy_limit(x, y) {
+            return Err(ProjectionError::OutOfDomain(lon, lat));
+        }
+
+        Ok((x, y))
+    }
+
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::OutOfDomain`] when the point is beyond the accuracy limit.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        if !self.within_accuracy_limit(x, y) {
+            return Err(ProjectionError::OutOfDomain(x, y));
+        }
+
+        let (lon, lat) = self.inverse_project_unchecked(x, y);
+
+        if !lon.is_finite() || !lat.is_finite() {
+            Err(ProjectionError::InverseProjectionImpossible(x, y))
+        } else {
+            Ok((lon, lat))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        match self.variant {
+            ModifiedAzimuthalEquidistantVariant::Micronesia => self.micronesia_project(lon, lat),
+            ModifiedAzimuthalEquidistantVariant::Guam => self.guam_project(lon, lat),
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        match self.variant {
+            ModifiedAzimuthalEquidistantVariant::Micronesia => {
+                self.micronesia_inverse_project(x, y)
+            }
+            ModifiedAzimuthalEquidistantVariant::Guam => self.guam_inverse_project(x, y),
+        }
+    }
 }
@@ -0,0 +1,174 @@
+//! The Mollweide projection is an equal-area, pseudocylindrical map projection generally used
+//! for global maps of the world or night sky. It is also known as the Babinet projection,
+//! homalographic projection, homolographic projection, and elliptical projection.
+//! The projection trades accuracy of angle and shape for accuracy of proportions in area,
+//! and as such is used where that property is needed, such as maps depicting global distributions
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Mollweide_projection).
+//!
+//! The forward projection solves for the auxiliary angle with Newton's method,
+//! the inverse projection is in closed form.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Pseudocylindrical.
+//! - Equal-area.
+//! - Central meridian is a straight line; 90th meridians are circular arcs, other meridians are equally spaced elliptical arcs.
+//! - Parallels are unequally spaced straight lines, parallel to each other.
+//! - Poles are points.
+//! - Scale is true along latitudes 40°44' N. and S.
+//! - Used for world maps, occasionally in interrupted form.
+//! - Presented by Mollweide in 1805.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, PI, SQRT_2};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Maximum number of iterations when solving for the auxiliary angle.
+const MAX_ITERATIONS: usize = 30;
+
+/// Main projection struct that is constructed from [`MollweideBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Mollweide {
+    lon_0: f64,
+    r: f64,
+}
+
+impl Mollweide {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> MollweideBuilder {
+        MollweideBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Mollweide`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct MollweideBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for MollweideBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl MollweideBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Mollweide projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Mollweide, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(Mollweide {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for Mollweide {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let (x, y) = unit_mollweide(lambda, phi);
+
+        (self.r * x, self.r * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let (lambda, phi) = unit_mollweide_inverse(x / self.r, y / self.r);
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Computes coordinates of the Mollweide projection on the unit sphere.
+pub(crate) fn unit_mollweide(lambda: f64, phi: f64) -> (f64, f64) {
+    // Formula (31-4) from Snyder (1987) solved for 2θ with Newton's method.
+    // Close to the poles the iteration converges slowly, but θ is there equal to φ.
+    let k = PI * phi.sin();
+    let mut two_theta = phi;
+    let mut theta = FRAC_PI_2.copysign(phi);
+
+    for _ in 0..MAX_ITERATIONS {
+        let d_theta = (two_theta + two_theta.sin() - k) / (1.0 + two_theta.cos());
+        two_theta -= d_theta;
+
+        if d_theta.abs() < 1e-7 {
+            theta = two_theta / 2.0;
+            break;
+        }
+    }
+
+    // Formulas (31-1) and (31-2) from Snyder (1987)
+    let x = 2.0 * SQRT_2 / PI * lambda * theta.cos();
+    let y = SQRT_2 * theta.sin();
+
+    (x, y)
+}
+
+/// Computes longitude and latitude (in radians, relative to the central meridian)
+/// from coordinates of the Mollweide projection on the unit sphere.
+/// Returns NaN for points outside of the map.
+pub(crate) fn unit_mollweide_inverse(x: f64, y: f64) -> (f64, f64) {
+    // Formulas (31-10) to (31-12) from Snyder (1987)
+    let theta = (y / SQRT_2).asin();
+    let lambda = PI * x / (2.0 * SQRT_2 * theta.cos());
+
+    if lambda.abs() > PI {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let phi = ((2.0 * theta + (2.0 * theta).sin()) / PI).asin();
+
+    (lambda, phi)
+}
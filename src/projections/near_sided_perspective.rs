@@ -0,0 +1,215 @@
+//! The general vertical near-side perspective projection is an azimuthal perspective projection
+//! of the globe viewed from a point at finite distance above the surface, so that it shows
+//! the Earth as it would be seen from a satellite
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/General_Perspective_projection).
+//!
+//! Points beyond the horizon visible from the perspective point return
+//! [`ProjectionError::PointNotVisible`] from checked [`project`](Projection::project).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Azimuthal.
+//! - Neither conformal nor equal-area.
+//! - Central meridian and a particular parallel (if shown) are straight lines.
+//! - Other meridians and parallels are usually arcs of circles or ellipses, but some may be parabolas or hyperbolas.
+//! - Directions from the center are true.
+//! - The Earth appears as it would from a finite distance in space.
+//! - Less than one hemisphere is shown.
+//! - Used to show the Earth or other planets and satellites as seen from space.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`NearSidedPerspectiveBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct NearSidedPerspective {
+    lambda_0: f64,
+    phi_0: f64,
+    sin_phi_0: f64,
+    cos_phi_0: f64,
+    p: f64,
+    r: f64,
+}
+
+impl NearSidedPerspective {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> NearSidedPerspectiveBuilder {
+        NearSidedPerspectiveBuilder::default()
+    }
+
+    /// Cosine of the angular distance from the center of projection, Snyder (1987) eq. (5-3).
+    fn cos_c(&self, lambda: f64, phi: f64) -> f64 {
+        self.sin_phi_0
+            .mul_add(phi.sin(), self.cos_phi_0 * phi.cos() * lambda.cos())
+    }
+}
+
+/// Builder struct which allows to construct [`NearSidedPerspective`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct NearSidedPerspectiveBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    height: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for NearSidedPerspectiveBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            height: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl NearSidedPerspectiveBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    /// The perspective point is located vertically above it.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(required)* Sets height of the perspective point (eg. satellite) above the surface in meters.
+    pub const fn height(&mut self, height: f64) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for the sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Near-sided perspective projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - reference longitude is not within -180..180 range.
+    /// - reference latitude is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    /// - height is not positive.
+    pub fn initialize_projection(&self) -> Result<NearSidedPerspective, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let height = unpack_required_parameter!(self, height);
+        ensure_finite!(ref_lon, ref_lat, height);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&ref_lat) {
+            return Err(ProjectionError::ParamOutOfRange("ref_lat", -90.0, 90.0));
+        }
+
+        if height <= 0.0 {
+            return Err(ProjectionError::IncorrectParams("height must be positive"));
+        }
+
+        let r = self.ellipsoid.A;
+        let phi_0 = ref_lat.to_radians();
+
+        Ok(NearSidedPerspective {
+            lambda_0: ref_lon.to_radians(),
+            phi_0,
+            sin_phi_0: phi_0.sin(),
+            cos_phi_0: phi_0.cos(),
+            p: 1.0 + height / r,
+            r,
+        })
+    }
+}
+
+impl Projection for NearSidedPerspective {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::PointNotVisible`] when the point is beyond the horizon.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        if self.cos_c(lon.to_radians() - self.lambda_0, lat.to_radians()) < 1.0 / self.p {
+            return Err(ProjectionError::PointNotVisible(lon, lat));
+        }
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = lon.to_radians() - self.lambda_0;
+
+        // Formulas (23-2) to (23-5) from Snyder (1987)
+        let k = (self.p - 1.0) / (self.p - self.cos_c(lambda, phi));
+
+        let x = self.r * k * phi.cos() * lambda.sin();
+        let y = self.r
+            * k
+            * self
+                .cos_phi_0
+                .mul_add(phi.sin(), -(self.sin_phi_0 * phi.cos() * lambda.cos()));
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = x.hypot(y);
+
+        if approx_eq!(f64, rho, 0.0) {
+            return (self.lambda_0.to_degrees(), self.phi_0.to_degrees());
+        }
+
+        // Formulas (23-18) to (23-20) from Snyder (1987)
+        let r_p = self.r * (self.p - 1.0);
+        let sin_c = (self.p
+            - (rho * rho * (self.p + 1.0) / (self.r * r_p))
+                .mul_add(-1.0, 1.0)
+                .sqrt())
+            / (r_p / rho + rho / r_p);
+        let cos_c = sin_c.mul_add(-sin_c, 1.0).sqrt();
+
+        let phi = cos_c
+            .mul_add(self.sin_phi_0, y * sin_c * self.cos_phi_0 / rho)
+            .asin();
+        let lambda = (x * sin_c)
+            .atan2((rho * self.cos_phi_0).mul_add(cos_c, -(y * self.sin_phi_0 * sin_c)))
+            + self.lambda_0;
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
@@ -0,0 +1,99 @@
+//! Numerical inverse for projections which do not have closed-form inverse formulas.
+
+use crate::ProjectionError;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Maximum number of iterations of Newton's method.
+const MAX_ITERATIONS: usize = 30;
+
+/// Tolerance of coordinates on the unit sphere (about 6 µm on the Earth).
+const TOLERANCE: f64 = 1e-12;
+
+/// Step in radians used for approximating the Jacobian with finite differences.
+const STEP: f64 = 1e-6;
+
+/// Finds longitude and latitude (in radians, relative to the central meridian) for which
+/// `forward` returns given coordinates, scaled by the radius or semi-major axis, using Newton's method
+/// with the Jacobian approximated by finite differences. Iteration starts from `initial`
+/// longitude and latitude, which should be a reasonable approximation of the solution.
+///
+/// Iterates are kept within the domain of the projection, so `None` is returned both
+/// when the iteration does not converge and when the point is outside of the map.
+pub(crate) fn solve<F>(forward: F, x: f64, y: f64, initial: (f64, f64)) -> Option<(f64, f64)>
+where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    let mut lambda = initial.0.clamp(-PI, PI);
+    let mut phi = initial.1.clamp(-FRAC_PI_2, FRAC_PI_2);
+
+    for _ in 0..MAX_ITERATIONS {
+        let (f_x, f_y) = forward(lambda, phi);
+        let r_x = f_x - x;
+        let r_y = f_y - y;
+
+        if r_x.abs() < TOLERANCE && r_y.abs() < TOLERANCE {
+            return Some((lambda, phi));
+        }
+
+        // Differences are taken within the domain, as the map edge can be outside of it
+        let lambda_1 = (lambda + STEP).min(PI);
+        let lambda_0 = (lambda - STEP).max(-PI);
+        let phi_1 = (phi + STEP).min(FRAC_PI_2);
+        let phi_0 = (phi - STEP).max(-FRAC_PI_2);
+
+        // Partial derivatives of (x, y) with respect to longitude and latitude
+        let by_lambda = difference(
+            forward(lambda_1, phi),
+            forward(lambda_0, phi),
+            lambda_1 - lambda_0,
+        );
+        let by_phi = difference(
+            forward(lambda, phi_1),
+            forward(lambda, phi_0),
+            phi_1 - phi_0,
+        );
+
+        let det = by_lambda.0.mul_add(by_phi.1, -(by_phi.0 * by_lambda.1));
+
+        if !det.is_normal() {
+            return None;
+        }
+
+        let d_lambda = r_x.mul_add(by_phi.1, -(r_y * by_phi.0)) / det;
+        let d_phi = r_y.mul_add(by_lambda.0, -(r_x * by_lambda.1)) / det;
+
+        lambda = (lambda - d_lambda).clamp(-PI, PI);
+        phi = (phi - d_phi).clamp(-FRAC_PI_2, FRAC_PI_2);
+    }
+
+    None
+}
+
+/// Converts the result of the numerical inverse of point (`x`, `y`) to the result of checked
+/// [`inverse_project`](crate::Projection::inverse_project), returning [`ProjectionError::InverseNotConverged`]
+/// when the inverse did not converge.
+pub(crate) fn checked(
+    result: Option<(f64, f64)>,
+    x: f64,
+    y: f64,
+) -> Result<(f64, f64), ProjectionError> {
+    let (lon, lat) = result.ok_or(ProjectionError::InverseNotConverged(x, y))?;
+
+    if !lon.is_finite() || !lat.is_finite() {
+        Err(ProjectionError::InverseProjectionImpossible(x, y))
+    } else {
+        Ok((lon, lat))
+    }
+}
+
+/// Converts the result of the numerical inverse to the result of
+/// [`inverse_project_unchecked`](crate::Projection::inverse_project_unchecked),
+/// which is `NaN` when the inverse did not converge.
+pub(crate) fn unchecked(result: Option<(f64, f64)>) -> (f64, f64) {
+    result.unwrap_or((f64::NAN, f64::NAN))
+}
+
+/// Computes finite differences of both coordinates over given step.
+fn difference(upper: (f64, f64), lower: (f64, f64), step: f64) -> (f64, f64) {
+    ((upper.0 - lower.0) / step, (upper.1 - lower.1) / step)
+}
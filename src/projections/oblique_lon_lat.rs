@@ -9,16 +9,38 @@
 //! regional mapping where the area of interest can be positioned optimally relative
 //! to the coordinate grid, reducing distortion in the region of interest.
 //!
+//! By default geodetic latitudes are used directly as latitudes on the sphere.
+//! Some models (e.g. ICON-LAM and some COSMO setups) define the rotation on a sphere
+//! to which geodetic latitudes are mapped as geocentric or conformal latitudes,
+//! which can be chosen with [`LatitudeMode`].
+//!
 //! Unlike most other projections in this crate, the output of the
 //! [`project`](crate::Projection::project) function is in degrees, not meters.
 
 use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
 use crate::errors::ProjectionError;
 use crate::errors::{ensure_finite, ensure_within_range, unpack_required_parameter};
+use crate::projections::lambert_conformal_conic::{phi_for_inverse, t};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
 
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
+/// Treatment of geodetic latitudes before the rotation of the sphere.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum LatitudeMode {
+    /// Geodetic latitudes are used as spherical latitudes without any conversion.
+    #[default]
+    Spherical,
+
+    /// Geodetic latitudes are converted to geocentric latitudes on the ellipsoid.
+    Geocentric,
+
+    /// Geodetic latitudes are converted to conformal latitudes on the ellipsoid.
+    Conformal,
+}
+
 /// Main projection struct that is constructed from [`ObliqueLonLatBuilder`] and used for computations.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub struct ObliqueLonLat {
@@ -26,6 +48,8 @@ pub struct ObliqueLonLat {
     sin_phi_p: f64,
     cos_phi_p: f64,
     lon_0: f64,
+    mode: LatitudeMode,
+    ellps: Ellipsoid,
 }
 
 impl ObliqueLonLat {
@@ -37,6 +61,26 @@ impl ObliqueLonLat {
     pub fn builder() -> ObliqueLonLatBuilder {
         ObliqueLonLatBuilder::default()
     }
+
+    /// Converts geodetic latitude to the latitude on the rotated sphere.
+    fn sphere_lat(&self, phi: f64) -> f64 {
+        match self.mode {
+            LatitudeMode::Spherical => phi,
+            LatitudeMode::Geocentric => ((1.0 - self.ellps.E.powi(2)) * phi.sin()).atan2(phi.cos()),
+            LatitudeMode::Conformal => 2.0f64.mul_add(-t(phi, self.ellps).atan(), FRAC_PI_2),
+        }
+    }
+
+    /// Converts latitude on the rotated sphere back to geodetic latitude.
+    fn geodetic_lat(&self, phi: f64) -> f64 {
+        match self.mode {
+            LatitudeMode::Spherical => phi,
+            LatitudeMode::Geocentric => phi.sin().atan2((1.0 - self.ellps.E.powi(2)) * phi.cos()),
+            LatitudeMode::Conformal => {
+                phi_for_inverse(0.5f64.mul_add(-phi, FRAC_PI_4).tan(), self.ellps)
+            }
+        }
+    }
 }
 
 /// Builder struct which allows to construct [`ObliqueLonLat`] projection.
@@ -47,6 +91,8 @@ pub struct ObliqueLonLatBuilder {
     pole_lon: Option<f64>,
     pole_lat: Option<f64>,
     central_lon: f64,
+    mode: LatitudeMode,
+    ellipsoid: Ellipsoid,
 }
 
 impl Default for ObliqueLonLatBuilder {
@@ -55,6 +101,8 @@ impl Default for ObliqueLonLatBuilder {
             pole_lon: None,
             pole_lat: None,
             central_lon: 0.0,
+            mode: LatitudeMode::Spherical,
+            ellipsoid: Ellipsoid::WGS84,
         }
     }
 }
@@ -73,6 +121,20 @@ impl ObliqueLonLatBuilder {
         self
     }
 
+    /// *(optional)* Sets how geodetic latitudes are treated before the rotation,
+    /// defaults to [`LatitudeMode::Spherical`].
+    pub const fn latitude_mode(&mut self, mode: LatitudeMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// It is used only for [`LatitudeMode::Geocentric`] and [`LatitudeMode::Conformal`].
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
     /// `ObliqueLonLat` projection constructor.
     ///
     /// To reduce computational overhead of projection functions this
@@ -81,8 +143,6 @@ impl ObliqueLonLatBuilder {
     /// When projecting multiple coordinates only one instance of the structure should be created
     /// and copied/borrowed as needed.
     ///
-    /// Ellipsoid is not definable as this projection does not depend on it.
-    ///
     /// # Errors
     ///
     /// Returns [`ProjectionError`] with additional information when:
@@ -107,11 +167,13 @@ impl ObliqueLonLatBuilder {
             sin_phi_p: phi_p.sin(),
             cos_phi_p: phi_p.cos(),
             lon_0: central_lon,
+            mode: self.mode,
+            ellps: self.ellipsoid,
         })
     }
 }
 
-fn adjust_lon(lon: f64) -> f64 {
+pub(crate) fn adjust_lon(lon: f64) -> f64 {
     let pi_degrees = 180.0_f64;
     if lon > pi_degrees {
         2.0f64.mul_add(-pi_degrees, lon)
@@ -127,7 +189,7 @@ impl Projection for ObliqueLonLat {
     #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
     fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
         let lambda = (lon - self.lon_0).to_radians();
-        let phi = lat.to_radians();
+        let phi = self.sphere_lat(lat.to_radians());
 
         let cos_lambda = lambda.cos();
         let sin_lambda = lambda.sin();
@@ -168,7 +230,7 @@ impl Projection for ObliqueLonLat {
             .asin();
 
         let lon = adjust_lon(lambda.to_degrees() + self.lon_0);
-        let lat = phi.to_degrees();
+        let lat = self.geodetic_lat(phi).to_degrees();
         (lon, lat)
     }
 }
@@ -0,0 +1,213 @@
+//! The orthographic projection is a means of representing three-dimensional objects in two dimensions.
+//! As a map projection it is an azimuthal perspective projection, projecting the Earth's surface
+//! from an infinite distance to a plane, which gives the appearance of a globe seen from outer space
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Orthographic_map_projection).
+//!
+//! This implementation follows the ellipsoidal formulas of EPSG method 9840.
+//! Points on the far side of the globe return [`ProjectionError::PointNotVisible`]
+//! from checked [`project`](Projection::project).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Azimuthal.
+//! - All meridians and parallels are ellipses, circles, or straight lines.
+//! - Neither conformal nor equal-area.
+//! - Closely resembles a globe in appearance, since it is a perspective projection from infinite distance.
+//! - Only one hemisphere can be shown.
+//! - The Earth appears as it would from deep space.
+//! - Directions from the center are true.
+//! - Radial scale factor decreases as distance increases from the center.
+//! - Scale in the direction of the lines of latitude is true in the polar aspect.
+//! - Used chiefly for pictorial views.
+//! - Used first by the Egyptians and Greeks 2,000 years ago.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::oblique_lon_lat::adjust_lon;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`OrthographicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Orthographic {
+    lambda_0: f64,
+    sin_phi_0: f64,
+    cos_phi_0: f64,
+    y_offset: f64,
+    ellps: Ellipsoid,
+}
+
+impl Orthographic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> OrthographicBuilder {
+        OrthographicBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Orthographic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct OrthographicBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for OrthographicBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl OrthographicBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    /// It is the center of the visible hemisphere.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Orthographic projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - reference longitude is not within -180..180 range.
+    /// - reference latitude is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Orthographic, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&ref_lat) {
+            return Err(ProjectionError::ParamOutOfRange("ref_lat", -90.0, 90.0));
+        }
+
+        let phi_0 = ref_lat.to_radians();
+        let (sin_phi_0, cos_phi_0) = phi_0.sin_cos();
+
+        Ok(Orthographic {
+            lambda_0: ref_lon.to_radians(),
+            sin_phi_0,
+            cos_phi_0,
+            y_offset: ellps.E.powi(2) * nu(sin_phi_0, ellps) * sin_phi_0 * cos_phi_0,
+            ellps,
+        })
+    }
+}
+
+impl Projection for Orthographic {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::PointNotVisible`] when the point is on the far side of the globe.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        let (sin_phi, cos_phi) = lat.to_radians().sin_cos();
+        let cos_c = self.sin_phi_0.mul_add(
+            sin_phi,
+            self.cos_phi_0 * cos_phi * (lon.to_radians() - self.lambda_0).cos(),
+        );
+
+        if cos_c < 0.0 {
+            return Err(ProjectionError::PointNotVisible(lon, lat));
+        }
+
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (sin_phi, cos_phi) = lat.to_radians().sin_cos();
+        let (sin_lambda, cos_lambda) = (lon.to_radians() - self.lambda_0).sin_cos();
+        let nu = nu(sin_phi, self.ellps);
+
+        let x = nu * cos_phi * sin_lambda;
+        let y = nu.mul_add(
+            self.cos_phi_0
+                .mul_add(sin_phi, -(self.sin_phi_0 * cos_phi * cos_lambda)),
+            -(self.ellps.E.powi(2) * nu * sin_phi * self.cos_phi_0),
+        ) + self.y_offset;
+
+        (x, y)
+    }
+
+    #[inline]
+    #[allow(clippy::many_single_char_names)]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        // The projection is an orthogonal projection of geocentric coordinates
+        // onto the plane tangent at the center, so the inverse intersects
+        // the line normal to that plane with the ellipsoid on the visible side.
+        let y = y - self.y_offset;
+        let k = 1.0 / self.ellps.E.mul_add(-self.ellps.E, 1.0);
+
+        let p_0 = -y * self.sin_phi_0;
+        let z_0 = y * self.cos_phi_0;
+
+        let a = (k * self.sin_phi_0).mul_add(self.sin_phi_0, self.cos_phi_0.powi(2));
+        let b = 2.0 * p_0.mul_add(self.cos_phi_0, k * z_0 * self.sin_phi_0);
+        let c = (k * z_0).mul_add(z_0, p_0.mul_add(p_0, x * x)) - self.ellps.A.powi(2);
+
+        // Rounding errors can make the discriminant slightly negative for points on the horizon
+        let mut discriminant = b.mul_add(b, -4.0 * a * c);
+        if discriminant < 0.0 && discriminant > -1e-12 * self.ellps.A.powi(2) {
+            discriminant = 0.0;
+        }
+
+        let t = (-b + discriminant.sqrt()) / (2.0 * a);
+
+        let p_x = t.mul_add(self.cos_phi_0, p_0);
+        let p_z = t.mul_add(self.sin_phi_0, z_0);
+
+        let lambda = x.atan2(p_x) + self.lambda_0;
+        let phi = p_z.atan2(x.hypot(p_x) / k);
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
+
+/// Computes radius of curvature in the prime vertical.
+fn nu(sin_phi: f64, ellps: Ellipsoid) -> f64 {
+    ellps.A
+        / (ellps.E * sin_phi)
+            .mul_add(-(ellps.E * sin_phi), 1.0)
+            .sqrt()
+}
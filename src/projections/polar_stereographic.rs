@@ -0,0 +1,240 @@
+//! The stereographic projection is a perspective projection of the sphere, through a specific point
+//! on the sphere (the pole or center of projection), onto a plane (the projection plane) perpendicular
+//! to the diameter through the point [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Stereographic_map_projection).
+//!
+//! This module implements the polar aspect of the ellipsoidal stereographic projection,
+//! which is used by polar weather models and sea-ice products (eg. NSIDC grids,
+//! GRIB2 template 3.20 or WRF `map_proj=2`). The projection can be defined by scale factor
+//! at the pole (EPSG variant A) or by latitude of true scale (EPSG variant B).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Azimuthal.
+//! - Conformal.
+//! - The central meridian and a particular parallel (if shown) are straight lines.
+//! - All meridians on the polar aspect are straight lines.
+//! - All other meridians and parallels are shown as arcs of circles.
+//! - A perspective projection for the sphere.
+//! - Directions from the center of the projection are true (except on ellipsoidal oblique and equatorial aspects).
+//! - Scale increases away from the center of the projection.
+//! - Point opposite the center of the projection cannot be plotted.
+//! - Used for polar maps and miscellaneous special maps.
+//! - Apparently invented by Hipparchus (2nd century B.C.).
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::Hemisphere;
+use crate::projections::lambert_conformal_conic::{phi_for_inverse, t};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::FRAC_PI_2;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`PolarStereographicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct PolarStereographic {
+    lambda_0: f64,
+    rho_factor: f64,
+    pole_sign: f64,
+    ellps: Ellipsoid,
+}
+
+impl PolarStereographic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> PolarStereographicBuilder {
+        PolarStereographicBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`PolarStereographic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct PolarStereographicBuilder {
+    pole: Hemisphere,
+    vertical_lon: f64,
+    scale_factor: f64,
+    true_scale_lat: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for PolarStereographicBuilder {
+    fn default() -> Self {
+        Self {
+            pole: Hemisphere::North,
+            vertical_lon: 0.0,
+            scale_factor: 1.0,
+            true_scale_lat: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl PolarStereographicBuilder {
+    /// *(optional)* Sets the pole at the center of the projection, defaults to [`Hemisphere::North`].
+    pub const fn pole(&mut self, pole: Hemisphere) -> &mut Self {
+        self.pole = pole;
+        self
+    }
+
+    /// *(optional)* Sets the straight vertical longitude, which is the meridian projected
+    /// as a vertical line going down (for the north pole) or up (for the south pole) from the pole, defaults to `0.0`.
+    pub const fn straight_vertical_lon(&mut self, lon: f64) -> &mut Self {
+        self.vertical_lon = lon;
+        self
+    }
+
+    /// *(optional, alternative with [`true_scale_lat`](PolarStereographicBuilder::true_scale_lat))* Sets scale factor
+    /// at the pole (variant A), defaults to `1.0`.
+    pub const fn scale_factor(&mut self, k_0: f64) -> &mut Self {
+        self.scale_factor = k_0;
+        self.true_scale_lat = None;
+        self
+    }
+
+    /// *(optional, alternative with [`scale_factor`](PolarStereographicBuilder::scale_factor))* Sets latitude
+    /// along which scale is true (variant B). It must be in the hemisphere of the projection pole.
+    pub const fn true_scale_lat(&mut self, lat: f64) -> &mut Self {
+        self.true_scale_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Polar stereographic projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - straight vertical longitude is not within -180..180 range.
+    /// - latitude of true scale is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    /// - scale factor is not positive.
+    /// - latitude of true scale is in the hemisphere opposite to the projection pole.
+    pub fn initialize_projection(&self) -> Result<PolarStereographic, ProjectionError> {
+        let vertical_lon = self.vertical_lon;
+        let ellps = self.ellipsoid;
+        ensure_finite!(vertical_lon);
+        ensure_within_range!(vertical_lon, -180.0..180.0);
+
+        let pole_sign = match self.pole {
+            Hemisphere::North => 1.0,
+            Hemisphere::South => -1.0,
+        };
+
+        let rho_factor = if let Some(true_scale_lat) = self.true_scale_lat {
+            ensure_finite!(true_scale_lat);
+
+            if true_scale_lat.abs() > 90.0 {
+                return Err(ProjectionError::ParamOutOfRange(
+                    "true_scale_lat",
+                    -90.0,
+                    90.0,
+                ));
+            }
+
+            if true_scale_lat * pole_sign < 0.0 {
+                return Err(ProjectionError::IncorrectParams(
+                    "latitude of true scale must be in the hemisphere of the projection pole",
+                ));
+            }
+
+            let phi_c = (true_scale_lat * pole_sign).to_radians();
+
+            if (phi_c - FRAC_PI_2).abs() < f64::EPSILON {
+                pole_rho_factor(1.0, ellps)
+            } else {
+                let m_c = phi_c.cos()
+                    / (ellps.E * phi_c.sin())
+                        .mul_add(-(ellps.E * phi_c.sin()), 1.0)
+                        .sqrt();
+
+                ellps.A * m_c / t(phi_c, ellps)
+            }
+        } else {
+            let scale_factor = self.scale_factor;
+            ensure_finite!(scale_factor);
+
+            if scale_factor <= 0.0 {
+                return Err(ProjectionError::IncorrectParams(
+                    "scale factor must be positive",
+                ));
+            }
+
+            pole_rho_factor(scale_factor, ellps)
+        };
+
+        Ok(PolarStereographic {
+            lambda_0: vertical_lon.to_radians(),
+            rho_factor,
+            pole_sign,
+            ellps,
+        })
+    }
+}
+
+impl Projection for PolarStereographic {
+    /// Same as the default [`Projection::project()`] but additionally returns
+    /// [`ProjectionError::ProjectionImpossible`] for the pole opposite to the center of projection,
+    /// which is projected to infinity.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project(&self, lon: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        let (x, y) = self.project_unchecked(lon, lat);
+
+        if lat * self.pole_sign <= -90.0 || !x.is_finite() || !y.is_finite() {
+            Err(ProjectionError::ProjectionImpossible(lon, lat))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = (lat * self.pole_sign).to_radians();
+        let lambda = lon.to_radians() - self.lambda_0;
+
+        let rho = self.rho_factor * t(phi, self.ellps);
+
+        let x = rho * lambda.sin();
+        let y = -self.pole_sign * rho * lambda.cos();
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = x.hypot(y);
+        let t = rho / self.rho_factor;
+
+        let lambda = x.atan2(-self.pole_sign * y) + self.lambda_0;
+        let phi = self.pole_sign * phi_for_inverse(t, self.ellps);
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
+
+/// Computes ρ/t ratio from scale factor at the pole, Snyder (1987) eq. (21-33).
+fn pole_rho_factor(k_0: f64, ellps: Ellipsoid) -> f64 {
+    let e = ellps.E;
+    2.0 * ellps.A * k_0 / ((1.0 + e).powf(1.0 + e) * (1.0 - e).powf(1.0 - e)).sqrt()
+}
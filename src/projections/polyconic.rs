@@ -0,0 +1,246 @@
+//! The American polyconic projection is a map projection used for maps of the United States
+//! and its regions in the early 20th century. Each parallel is projected as a circular arc
+//! of a cone tangent at that parallel, so that parallels are non-concentric and the scale
+//! is true along the central meridian and along every parallel
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/American_polyconic_projection).
+//!
+//! This implementation follows ellipsoidal formulas from Snyder (1987), which also apply
+//! to the sphere. The latitude in the inverse is computed with Newton's method and
+//! [`ProjectionError::InverseNotConverged`] is returned from checked
+//! [`inverse_project`](Projection::inverse_project) when it does not converge.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Neither conformal nor equal-area.
+//! - Parallels of latitude (except for Equator) are arcs of nonconcentric circles.
+//! - Central meridian is a straight line; all other meridians are complex curves.
+//! - Scale is true along each parallel and along the central meridian, but no parallel is "standard".
+//! - Free of distortion only along the central meridian.
+//! - Used almost exclusively for slight variations in large-scale mapping of the United States
+//!   until the 1950s.
+//! - Apparently originated about 1820 by Hassler.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::equidistant_conic::meridian_distance;
+use crate::projections::lambert_conformal_conic::m;
+use crate::projections::numerical_inverse;
+use crate::projections::oblique_lon_lat::adjust_lon;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Maximum number of iterations in the inverse projection.
+const MAX_ITERATIONS: usize = 20;
+
+/// Tolerance of the latitude in the inverse projection.
+const TOLERANCE: f64 = 1e-12;
+
+/// Latitudes and northings (scaled by the semi-major axis) closer to zero are treated as the Equator.
+const EQUATOR_TOLERANCE: f64 = 1e-10;
+
+/// Main projection struct that is constructed from [`PolyconicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Polyconic {
+    lon_0: f64,
+    m_0: f64,
+    false_easting: f64,
+    false_northing: f64,
+    ellps: Ellipsoid,
+}
+
+impl Polyconic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> PolyconicBuilder {
+        PolyconicBuilder::default()
+    }
+
+    /// Computes the inverse projection with ellipsoidal formulas from Snyder (1987),
+    /// returns `None` when the latitude iteration does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let e2 = self.ellps.E.powi(2);
+        let x = (x - self.false_easting) / self.ellps.A;
+        let big_a = (y - self.false_northing + self.m_0) / self.ellps.A;
+
+        if big_a.abs() <= EQUATOR_TOLERANCE {
+            return Some((adjust_lon(self.lon_0 + x.to_degrees()), 0.0));
+        }
+
+        let big_b = x.mul_add(x, big_a * big_a);
+        let mut phi = big_a;
+        let mut converged = false;
+
+        for _ in 0..MAX_ITERATIONS {
+            let (sin_phi, cos_phi) = phi.sin_cos();
+
+            if cos_phi.abs() < TOLERANCE {
+                return None;
+            }
+
+            let sin_cos_phi = sin_phi * cos_phi;
+            let w = e2.mul_add(-sin_phi.powi(2), 1.0).sqrt();
+            let big_c = sin_phi * w / cos_phi;
+            let m_a = meridian_distance(phi, self.ellps) / self.ellps.A;
+            let m_b = m_a.mul_add(m_a, big_b);
+            let m_p = (1.0 - e2) / w.powi(3);
+
+            let numerator = big_c.mul_add(m_b, 2.0 * m_a) - 2.0 * big_a * big_c.mul_add(m_a, 1.0);
+            let denominator = (e2 * sin_cos_phi * (2.0 * big_a).mul_add(-m_a, m_b) / big_c)
+                + 2.0 * (big_a - m_a) * big_c.mul_add(m_p, -1.0 / sin_cos_phi)
+                - 2.0 * m_p;
+
+            let d_phi = numerator / denominator;
+            phi += d_phi;
+
+            if d_phi.abs() <= TOLERANCE {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return None;
+        }
+
+        let sin_phi = phi.sin();
+        let lambda = (x * phi.tan() * e2.mul_add(-sin_phi.powi(2), 1.0).sqrt()).asin() / sin_phi;
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+}
+
+/// Builder struct which allows to construct [`Polyconic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct PolyconicBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    false_easting: f64,
+    false_northing: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for PolyconicBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            false_easting: 0.0,
+            false_northing: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl PolyconicBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets false easting and false northing (in meters) added to projected coordinates, defaults to `(0.0, 0.0)`.
+    pub const fn false_origin(&mut self, easting: f64, northing: f64) -> &mut Self {
+        self.false_easting = easting;
+        self.false_northing = northing;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Polyconic projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - reference longitude is not within -180..180 range.
+    /// - reference latitude is not within -90..=90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Polyconic, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let false_easting = self.false_easting;
+        let false_northing = self.false_northing;
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat, false_easting, false_northing);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+
+        if !(-90.0..=90.0).contains(&ref_lat) {
+            return Err(ProjectionError::ParamOutOfRange("ref_lat", -90.0, 90.0));
+        }
+
+        Ok(Polyconic {
+            lon_0: ref_lon,
+            m_0: meridian_distance(ref_lat.to_radians(), ellps),
+            false_easting,
+            false_northing,
+            ellps,
+        })
+    }
+}
+
+impl Projection for Polyconic {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the latitude iteration does not converge.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Ellipsoidal forward formulas from Snyder (1987)
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let phi = lat.to_radians();
+
+        let (x, y) = if phi.abs() <= EQUATOR_TOLERANCE {
+            (self.ellps.A * lambda, -self.m_0)
+        } else {
+            let sin_phi = phi.sin();
+            let n_cot_phi = self.ellps.A * m(phi, self.ellps) / sin_phi;
+            let big_e = lambda * sin_phi;
+
+            (
+                n_cot_phi * big_e.sin(),
+                n_cot_phi.mul_add(
+                    1.0 - big_e.cos(),
+                    meridian_distance(phi, self.ellps) - self.m_0,
+                ),
+            )
+        };
+
+        (x + self.false_easting, y + self.false_northing)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
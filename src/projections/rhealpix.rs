@@ -0,0 +1,390 @@
+//! The `rHEALPix` projection is a modification of the [`HEALPix`](crate::projections::Healpix) projection,
+//! in which the triangular polar caps are rearranged into two squares, placed above and below
+//! one of the four equatorial squares. It is the projection of the `rHEALPix` discrete global grid,
+//! which divides the sphere into 6 square base cells of equal area, each divided into 9 cells
+//! at every next resolution [(Gibb et al., 2016)](https://doi.org/10.1080/10095020.2016.1161932).
+//!
+//! This implementation follows `rhealpix` projection from `Proj`. On the ellipsoid the authalic
+//! latitude is projected onto the sphere of the same surface area.
+//!
+//! Cells of the grid can be identified with [`RHealpix::cell_id`] and [`RHealpix::cell_center`].
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::healpix::{
+    EPS, authalic_lat, authalic_radius, cell_index, lat_from_authalic, unit_healpix,
+    unit_healpix_inverse,
+};
+use crate::projections::lambert_azimuthal_equal_area::q;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Maximum resolution of the grid, at which cell identifiers still fit into `u64`.
+pub const MAX_RESOLUTION: u8 = 19;
+
+/// Main projection struct that is constructed from [`RHealpixBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct RHealpix {
+    lon_0: f64,
+    north_square: u8,
+    south_square: u8,
+    r_q: f64,
+    q_p: f64,
+    ellps: Ellipsoid,
+}
+
+impl RHealpix {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> RHealpixBuilder {
+        RHealpixBuilder::default()
+    }
+
+    /// Returns the identifier of the cell containing given projected point at given resolution.
+    ///
+    /// Base cells N, O, P, Q, R and S are numbered from `0` to `5`, where N and S are the polar squares
+    /// and O to R are equatorial squares from west to east. At every resolution each cell is divided
+    /// into 9 cells, numbered from `0` to `8` in rows from the upper left corner. The identifier is
+    /// the number of the base cell followed by the numbers of the cells at every resolution,
+    /// as digits of the number in base 9. For example, cell Q35 of the `rHEALPix` grid has identifier
+    /// `3 * 9^2 + 3 * 9 + 5` at resolution 2.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - resolution is larger than [`MAX_RESOLUTION`].
+    /// - point is outside of the map.
+    /// - one or more arguments are not finite.
+    pub fn cell_id(&self, x: f64, y: f64, resolution: u8) -> Result<u64, ProjectionError> {
+        ensure_finite!(x, y);
+        ensure_valid_resolution(resolution)?;
+
+        let x_u = x / self.r_q;
+        let y_u = y / self.r_q;
+
+        if !self.in_image(x_u, y_u) {
+            return Err(ProjectionError::OutOfDomain(x, y));
+        }
+
+        let (base_cell, left, top) = if y_u > FRAC_PI_4 {
+            (
+                0,
+                square_center(self.north_square) - FRAC_PI_4,
+                3.0 * FRAC_PI_4,
+            )
+        } else if y_u < -FRAC_PI_4 {
+            (5, square_center(self.south_square) - FRAC_PI_4, -FRAC_PI_4)
+        } else {
+            let square = cap_number(x_u);
+            (
+                1 + u64::from(square),
+                square_center(square) - FRAC_PI_4,
+                FRAC_PI_4,
+            )
+        };
+
+        let nside = 3_u32.pow(u32::from(resolution));
+        let col = cell_index((x_u - left) / FRAC_PI_2, nside);
+        let row = cell_index((top - y_u) / FRAC_PI_2, nside);
+
+        let id = (0..resolution).rev().fold(base_cell, |id, level| {
+            let divisor = 3_u32.pow(u32::from(level));
+            let digit = 3 * (row / divisor % 3) + col / divisor % 3;
+
+            9 * id + u64::from(digit)
+        });
+
+        Ok(id)
+    }
+
+    /// Returns longitude and latitude of the center of the cell with given identifier at given resolution.
+    /// Refer to the documentation of [`RHealpix::cell_id`] for the description of identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - resolution is larger than [`MAX_RESOLUTION`].
+    /// - there is no cell with given identifier at given resolution.
+    pub fn cell_center(&self, cell_id: u64, resolution: u8) -> Result<(f64, f64), ProjectionError> {
+        ensure_valid_resolution(resolution)?;
+
+        let nside = 3_u32.pow(u32::from(resolution));
+
+        if cell_id >= 6 * u64::from(nside).pow(2) {
+            return Err(ProjectionError::InvalidCellId(cell_id, resolution));
+        }
+
+        let mut id = cell_id;
+        let mut col = 0;
+        let mut row = 0;
+
+        for level in 0..resolution {
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = (id % 9) as u32;
+            id /= 9;
+
+            let multiplier = 3_u32.pow(u32::from(level));
+            col += digit % 3 * multiplier;
+            row += digit / 3 * multiplier;
+        }
+
+        let (left, top) = match id {
+            0 => (
+                square_center(self.north_square) - FRAC_PI_4,
+                3.0 * FRAC_PI_4,
+            ),
+            5 => (square_center(self.south_square) - FRAC_PI_4, -FRAC_PI_4),
+            #[allow(clippy::cast_possible_truncation)]
+            square => (square_center(square as u8 - 1) - FRAC_PI_4, FRAC_PI_4),
+        };
+
+        let cell_size = FRAC_PI_2 / f64::from(nside);
+        let x = (f64::from(col) + 0.5).mul_add(cell_size, left);
+        let y = (f64::from(row) + 0.5).mul_add(-cell_size, top);
+
+        self.inverse_project(x * self.r_q, y * self.r_q)
+    }
+
+    /// Moves point in a polar cap of the `HEALPix` projection into the polar square.
+    fn combine_caps(&self, x: f64, y: f64) -> (f64, f64) {
+        if y.abs() <= FRAC_PI_4 {
+            return (x, y);
+        }
+
+        let cap = cap_number(x);
+
+        let (square, turns) = if y > 0.0 {
+            (
+                self.north_square,
+                i32::from(cap) - i32::from(self.north_square),
+            )
+        } else {
+            (
+                self.south_square,
+                i32::from(self.south_square) - i32::from(cap),
+            )
+        };
+
+        let pole_y = FRAC_PI_2.copysign(y);
+        let (dx, dy) = rotate(x - square_center(cap), y - pole_y, turns);
+
+        (dx + square_center(square), dy + pole_y)
+    }
+
+    /// Moves point in a polar square back into the polar cap of the `HEALPix` projection.
+    fn split_caps(&self, x: f64, y: f64) -> (f64, f64) {
+        if y.abs() <= FRAC_PI_4 {
+            return (x, y);
+        }
+
+        let square = if y > 0.0 {
+            self.north_square
+        } else {
+            self.south_square
+        };
+
+        let pole_y = FRAC_PI_2.copysign(y);
+        let dx = x - square_center(square);
+        let dy = y - pole_y;
+
+        // Caps are placed in the triangles of the square counterclockwise (north)
+        // or clockwise (south) from the triangle nearest to the Equator
+        let position = if dx > dy.abs() {
+            1
+        } else if -dx > dy.abs() {
+            3
+        } else if dy * y > 0.0 {
+            2
+        } else {
+            0
+        };
+
+        let cap = (square + position) % 4;
+
+        let turns = if y > 0.0 {
+            i32::from(cap) - i32::from(self.north_square)
+        } else {
+            i32::from(self.south_square) - i32::from(cap)
+        };
+
+        let (dx, dy) = rotate(dx, dy, -turns);
+
+        (dx + square_center(cap), dy + pole_y)
+    }
+
+    /// Checks if point on the unit sphere is within the image of the `rHEALPix` projection.
+    fn in_image(&self, x: f64, y: f64) -> bool {
+        if y.abs() <= FRAC_PI_4 {
+            return x.abs() <= PI + EPS;
+        }
+
+        let square = if y > 0.0 {
+            self.north_square
+        } else {
+            self.south_square
+        };
+
+        y.abs() <= 3.0 * FRAC_PI_4 + EPS && (x - square_center(square)).abs() <= FRAC_PI_4 + EPS
+    }
+}
+
+/// Builder struct which allows to construct [`RHealpix`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct RHealpixBuilder {
+    central_lon: f64,
+    north_square: u8,
+    south_square: u8,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for RHealpixBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            north_square: 0,
+            south_square: 0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl RHealpixBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets the position (from `0` to `3`, counting from the west) of the equatorial square
+    /// above which the north polar square is placed, defaults to `0`.
+    pub const fn north_square(&mut self, position: u8) -> &mut Self {
+        self.north_square = position;
+        self
+    }
+
+    /// *(optional)* Sets the position (from `0` to `3`, counting from the west) of the equatorial square
+    /// below which the south polar square is placed, defaults to `0`.
+    pub const fn south_square(&mut self, position: u8) -> &mut Self {
+        self.south_square = position;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// `rHEALPix` projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - position of north or south square is not within 0..=3 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<RHealpix, ProjectionError> {
+        let central_lon = self.central_lon;
+        let ellps = self.ellipsoid;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        let north_square = f64::from(self.north_square);
+        let south_square = f64::from(self.south_square);
+        ensure_within_range!(north_square, 0.0..4.0);
+        ensure_within_range!(south_square, 0.0..4.0);
+
+        let q_p = q(FRAC_PI_2, ellps);
+
+        Ok(RHealpix {
+            lon_0: central_lon,
+            north_square: self.north_square,
+            south_square: self.south_square,
+            r_q: authalic_radius(q_p, ellps),
+            q_p,
+            ellps,
+        })
+    }
+}
+
+impl Projection for RHealpix {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let beta = authalic_lat(lat.to_radians(), self.q_p, self.ellps);
+
+        let (x, y) = unit_healpix(lambda, beta);
+        let (x, y) = self.combine_caps(x, y);
+
+        (self.r_q * x, self.r_q * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = x / self.r_q;
+        let y = y / self.r_q;
+
+        if !self.in_image(x, y) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let (x, y) = self.split_caps(x, y);
+        let (lambda, beta) = unit_healpix_inverse(x, y);
+        let phi = lat_from_authalic(beta, self.q_p, self.ellps);
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
+
+/// Returns the number of the polar cap of the `HEALPix` projection (or equatorial square) containing given easting.
+fn cap_number(x: f64) -> u8 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let cap = (2.0 * x / PI + 2.0).floor().clamp(0.0, 3.0) as u8;
+
+    cap
+}
+
+/// Returns easting of the center of the square (or polar cap) with given number.
+fn square_center(square: u8) -> f64 {
+    FRAC_PI_2.mul_add(f64::from(square), -3.0 * FRAC_PI_4)
+}
+
+/// Rotates the vector counterclockwise by given number of quarter turns.
+fn rotate(x: f64, y: f64, turns: i32) -> (f64, f64) {
+    match turns.rem_euclid(4) {
+        0 => (x, y),
+        1 => (-y, x),
+        2 => (-x, -y),
+        _ => (y, -x),
+    }
+}
+
+fn ensure_valid_resolution(resolution: u8) -> Result<(), ProjectionError> {
+    let resolution = f64::from(resolution);
+    ensure_within_range!(resolution, 0.0..f64::from(MAX_RESOLUTION) + 1.0);
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+//! The Robinson projection is a map projection of a world map that shows the entire world at once.
+//! It was specifically created in an attempt to find a good compromise to the problem
+//! of readily showing the whole globe as a flat image. It was devised by Arthur H. Robinson in 1963
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Robinson_projection).
+//!
+//! The projection is defined by a table of lengths of parallels and their distances from the Equator
+//! at 5° intervals. This implementation interpolates that table with cubic polynomials
+//! using the same coefficients as Proj, and the inverse solves the interpolating polynomial
+//! with Newton's method.
+//!
+//! Summary by [Snyder (1993)](https://press.uchicago.edu/ucp/books/book/chicago/F/bo3632853.html):
+//!
+//! - Pseudocylindrical.
+//! - Neither conformal nor equal-area.
+//! - Central meridian and all parallels are straight lines, other meridians are elliptical arcs.
+//! - Parallels are equally spaced between 38°N and 38°S, spacing decreases beyond these limits.
+//! - Poles are lines 0.53 as long as the Equator.
+//! - Scale is true along 38°N and 38°S.
+//! - Used for world maps.
+//! - Presented by Robinson in 1974.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Number of 5° intervals in the table.
+const NODES: u8 = 18;
+
+/// Maximum number of iterations in the inverse projection.
+const MAX_ITERATIONS: usize = 100;
+
+/// Scaling of the tabulated lengths of parallels.
+const FXC: f64 = 0.8487;
+
+/// Scaling of the tabulated distances of parallels from the Equator.
+const FYC: f64 = 1.3523;
+
+/// Coefficients of cubic polynomials interpolating lengths of parallels within 5° intervals.
+/// They are kept in single precision, exactly as in Proj, so that the results are consistent.
+const X_COEFS: [[f32; 4]; NODES as usize + 1] = [
+    [1.0, 2.2199e-17, -7.15515e-05, 3.1103e-06],
+    [0.9986, -0.000_482_243, -2.4897e-05, -1.3309e-06],
+    [0.9954, -0.000_831_03, -4.48605e-05, -9.86701e-07],
+    [0.99, -0.001_353_64, -5.9661e-05, 3.6777e-06],
+    [0.9822, -0.001_674_42, -4.49547e-06, -5.72411e-06],
+    [0.973, -0.002_148_68, -9.03571e-05, 1.8736e-08],
+    [0.96, -0.003_050_85, -9.00761e-05, 1.64917e-06],
+    [0.9427, -0.003_827_92, -6.53386e-05, -2.6154e-06],
+    [0.9216, -0.004_677_46, -0.000_104_57, 4.81243e-06],
+    [0.8962, -0.005_362_23, -3.23831e-05, -5.43432e-06],
+    [0.8679, -0.006_093_63, -0.000_113_898, 3.32484e-06],
+    [0.835, -0.006_983_25, -6.40253e-05, 9.34959e-07],
+    [0.7986, -0.007_553_38, -5.00009e-05, 9.35324e-07],
+    [0.7597, -0.007_983_24, -3.5971e-05, -2.27626e-06],
+    [0.7186, -0.008_513_67, -7.01149e-05, -8.6303e-06],
+    [0.6732, -0.009_862_09, -0.000_199_569, 1.91974e-05],
+    [0.6213, -0.010_418, 8.83923e-05, 6.24051e-06],
+    [0.5722, -0.009_066_01, 0.000_182, 6.24051e-06],
+    [0.5322, -0.006_777_97, 0.000_275_608, 6.24051e-06],
+];
+
+/// Coefficients of cubic polynomials interpolating distances of parallels from the Equator within 5° intervals.
+/// They are kept in single precision, exactly as in Proj, so that the results are consistent.
+const Y_COEFS: [[f32; 4]; NODES as usize + 1] = [
+    [-5.20417e-18, 0.0124, 1.21431e-18, -8.45284e-11],
+    [0.062, 0.0124, -1.26793e-09, 4.22642e-10],
+    [0.124, 0.0124, 5.07171e-09, -1.60604e-09],
+    [0.186, 0.012_399_9, -1.90189e-08, 6.00152e-09],
+    [0.248, 0.012_400_2, 7.10039e-08, -2.24e-08],
+    [0.31, 0.012_399_2, -2.64997e-07, 8.35986e-08],
+    [0.372, 0.012_402_9, 9.88983e-07, -3.11994e-07],
+    [0.434, 0.012_389_3, -3.69093e-06, -4.35621e-07],
+    [0.4958, 0.012_319_8, -1.02252e-05, -3.45523e-07],
+    [0.5571, 0.012_191_6, -1.54081e-05, -5.82288e-07],
+    [0.6176, 0.011_993_8, -2.41424e-05, -5.25327e-07],
+    [0.6769, 0.011_713, -3.20223e-05, -5.16405e-07],
+    [0.7346, 0.011_354_1, -3.97684e-05, -6.09052e-07],
+    [0.7903, 0.010_910_7, -4.89042e-05, -1.04739e-06],
+    [0.8435, 0.010_343_1, -6.4615e-05, -1.40374e-09],
+    [0.8936, 0.009_696_86, -6.4636e-05, -8.547e-06],
+    [0.9394, 0.008_409_47, -0.000_192_841, -4.2106e-06],
+    [0.9761, 0.006_165_27, -0.000_256, -4.2106e-06],
+    [1.0, 0.003_289_47, -0.000_319_159, -4.2106e-06],
+];
+
+/// Main projection struct that is constructed from [`RobinsonBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Robinson {
+    lon_0: f64,
+    r: f64,
+}
+
+impl Robinson {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> RobinsonBuilder {
+        RobinsonBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Robinson`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct RobinsonBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for RobinsonBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl RobinsonBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Robinson projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Robinson, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(Robinson {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for Robinson {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+
+        // Index of the 5° interval and offset within it in degrees
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let i = ((lat.abs() / 5.0 + 1e-15).floor() as u8).min(NODES);
+        let d_phi = 5.0f64.mul_add(-f64::from(i), lat.abs());
+
+        let x = self.r * FXC * polynomial(X_COEFS[usize::from(i)], d_phi) * lambda;
+        let y = self.r * FYC * polynomial(Y_COEFS[usize::from(i)], d_phi);
+
+        (x, y.copysign(lat))
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let lambda = x / (self.r * FXC);
+        let y_abs = (y / (self.r * FYC)).abs();
+
+        if y_abs >= 1.0 {
+            // Allow small rounding error at the poles
+            if y_abs > 1.000_001 {
+                return (f64::NAN, f64::NAN);
+            }
+
+            let lambda = lambda / f64::from(X_COEFS[usize::from(NODES)][0]);
+
+            return (
+                adjust_lon(self.lon_0 + lambda.to_degrees()),
+                FRAC_PI_2.copysign(y).to_degrees(),
+            );
+        }
+
+        // Find the interval containing y, starting from the linear estimate
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mut i = ((y_abs * f64::from(NODES)).floor() as u8).min(NODES - 1);
+        loop {
+            if f64::from(Y_COEFS[usize::from(i)][0]) > y_abs {
+                i -= 1;
+            } else if f64::from(Y_COEFS[usize::from(i) + 1][0]) <= y_abs {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        let y_coefs = Y_COEFS[usize::from(i)];
+        let y_0 = f64::from(y_coefs[0]);
+        let y_1 = f64::from(Y_COEFS[usize::from(i) + 1][0]);
+
+        // Newton's method starting from the linear interpolation
+        let mut t = 5.0 * (y_abs - y_0) / (y_1 - y_0);
+        let mut converged = false;
+
+        for _ in 0..MAX_ITERATIONS {
+            let d_t = (polynomial(y_coefs, t) - y_abs) / derivative(y_coefs, t);
+            t -= d_t;
+
+            if d_t.abs() < 1e-10 {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let lambda = lambda / polynomial(X_COEFS[usize::from(i)], t);
+
+        if lambda.abs() > PI {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let lat = 5.0f64.mul_add(f64::from(i), t).copysign(y);
+
+        (adjust_lon(self.lon_0 + lambda.to_degrees()), lat)
+    }
+}
+
+/// Evaluates cubic polynomial with given coefficients.
+fn polynomial(coefs: [f32; 4], z: f64) -> f64 {
+    let [c_0, c_1, c_2, c_3] = coefs.map(f64::from);
+    z.mul_add(z.mul_add(z.mul_add(c_3, c_2), c_1), c_0)
+}
+
+/// Evaluates derivative of cubic polynomial with given coefficients.
+fn derivative(coefs: [f32; 4], z: f64) -> f64 {
+    let [_, c_1, c_2, c_3] = coefs.map(f64::from);
+    (3.0 * z).mul_add(z * c_3, (2.0 * z).mul_add(c_2, c_1))
+}
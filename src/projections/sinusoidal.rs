@@ -0,0 +1,149 @@
+//! The sinusoidal projection is a pseudocylindrical equal-area map projection, sometimes called
+//! the Sanson–Flamsteed or the Mercator equal-area projection. It is used by NASA
+//! for MODIS land products and for other global rasters
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Sinusoidal_projection).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Pseudocylindrical.
+//! - Equal-area.
+//! - Central meridian is a straight line; all other meridians are shown as equally spaced sinusoidal curves.
+//! - Parallels are equally spaced straight lines, parallel to each other.
+//! - Poles are points.
+//! - Scale is true along the central meridian and all parallels.
+//! - Used for world maps illustrating area characteristics, especially if interrupted.
+//! - Used for several maps of South America and Africa.
+//! - Developed in the 16th century; used by Mercator for maps of South America in 1606.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::equidistant_conic::{meridian_distance, phi_from_meridian_distance};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`SinusoidalBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Sinusoidal {
+    lon_0: f64,
+    ellps: Ellipsoid,
+}
+
+impl Sinusoidal {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> SinusoidalBuilder {
+        SinusoidalBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Sinusoidal`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct SinusoidalBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for SinusoidalBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl SinusoidalBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Sinusoidal projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<Sinusoidal, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(Sinusoidal {
+            lon_0: central_lon,
+            ellps: self.ellipsoid,
+        })
+    }
+}
+
+impl Projection for Sinusoidal {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        // Formulas (30-8) and (30-9) from Snyder (1987)
+        let x = self.ellps.A * lambda * cos_phi
+            / (self.ellps.E * sin_phi)
+                .mul_add(-(self.ellps.E * sin_phi), 1.0)
+                .sqrt();
+        let y = meridian_distance(phi, self.ellps);
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let phi = phi_from_meridian_distance(y, self.ellps);
+
+        // Formula (30-11) from Snyder (1987), longitude is undefined at the poles
+        let lambda = if phi.abs() < FRAC_PI_2 {
+            let sin_phi = phi.sin();
+            x * (self.ellps.E * sin_phi)
+                .mul_add(-(self.ellps.E * sin_phi), 1.0)
+                .sqrt()
+                / (self.ellps.A * phi.cos())
+        } else if phi.abs() - 1e-10 < FRAC_PI_2 {
+            0.0
+        } else {
+            return (f64::NAN, f64::NAN);
+        };
+
+        // Points beyond the edge of the map
+        if lambda.abs() > PI {
+            return (f64::NAN, f64::NAN);
+        }
+
+        (
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        )
+    }
+}
@@ -0,0 +1,356 @@
+//! The stereographic projection is a perspective projection of the sphere, through a specific point
+//! on the sphere (the pole or center of projection), onto a plane (the projection plane) perpendicular
+//! to the diameter through the point [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Stereographic_map_projection).
+//!
+//! This module implements the oblique and equatorial aspects of the stereographic projection
+//! in two ellipsoidal forms, selectable with [`StereographicVariant`]:
+//!
+//! - Oblique Stereographic (EPSG method 9809), which projects the ellipsoid on the conformal sphere first,
+//!   as used by eg. the Dutch RD and Canadian grids.
+//! - Ellipsoidal stereographic from [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395), which uses conformal latitude.
+//!
+//! For the polar aspect use [`PolarStereographic`](crate::projections::PolarStereographic).
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Azimuthal.
+//! - Conformal.
+//! - The central meridian and a particular parallel (if shown) are straight lines.
+//! - All meridians on the polar aspect are straight lines.
+//! - All other meridians and parallels are shown as arcs of circles.
+//! - A perspective projection for the sphere.
+//! - Directions from the center of the projection are true (except on ellipsoidal oblique and equatorial aspects).
+//! - Scale increases away from the center of the projection.
+//! - Point opposite the center of the projection cannot be plotted.
+//! - Used for polar maps and miscellaneous special maps.
+//! - Apparently invented by Hipparchus (2nd century B.C.).
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::lambert_conformal_conic::{phi_for_inverse, t};
+use crate::projections::oblique_lon_lat::adjust_lon;
+use float_cmp::approx_eq;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Ellipsoidal form of the stereographic projection.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum StereographicVariant {
+    /// Oblique Stereographic (EPSG method 9809), also known as Double Stereographic.
+    /// The ellipsoid is first conformally projected on a sphere and
+    /// then the spherical stereographic projection is applied (equivalent to `sterea` in Proj).
+    #[default]
+    Oblique,
+
+    /// Ellipsoidal stereographic projection as described by Snyder (1987),
+    /// which uses conformal latitude (equivalent to `stere` in Proj).
+    Snyder,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+enum VariantParams {
+    Oblique {
+        n: f64,
+        c: f64,
+        sin_chi_0: f64,
+        cos_chi_0: f64,
+        chi_0: f64,
+        two_r_k_0: f64,
+    },
+    Snyder {
+        sin_chi_1: f64,
+        cos_chi_1: f64,
+        two_a_k_0_m_1: f64,
+    },
+}
+
+/// Main projection struct that is constructed from [`StereographicBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Stereographic {
+    lambda_0: f64,
+    params: VariantParams,
+    ellps: Ellipsoid,
+}
+
+impl Stereographic {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> StereographicBuilder {
+        StereographicBuilder::default()
+    }
+}
+
+/// Builder struct which allows to construct [`Stereographic`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct StereographicBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    scale_factor: f64,
+    variant: StereographicVariant,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for StereographicBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            scale_factor: 1.0,
+            variant: StereographicVariant::default(),
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl StereographicBuilder {
+    /// *(required)* Sets reference longitude and latitude. Point (0, 0) on the map will be at this coordinates.
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self
+    }
+
+    /// *(optional)* Sets scale factor at the center of projection, defaults to `1.0`.
+    pub const fn scale_factor(&mut self, k_0: f64) -> &mut Self {
+        self.scale_factor = k_0;
+        self
+    }
+
+    /// *(optional)* Sets the ellipsoidal form of the projection, defaults to [`StereographicVariant::Oblique`].
+    pub const fn variant(&mut self, variant: StereographicVariant) -> &mut Self {
+        self.variant = variant;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Stereographic projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - one or more longitudes are not within -180..180 range.
+    /// - one or more latitudes are not within -90..90 range.
+    /// - one or more arguments are not finite.
+    /// - reference latitude is a pole, as polar aspect is handled by [`PolarStereographic`](crate::projections::PolarStereographic).
+    /// - scale factor is not positive.
+    pub fn initialize_projection(&self) -> Result<Stereographic, ProjectionError> {
+        let ref_lon = unpack_required_parameter!(self, ref_lon);
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let scale_factor = self.scale_factor;
+        let ellps = self.ellipsoid;
+        ensure_finite!(ref_lon, ref_lat, scale_factor);
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+        ensure_within_range!(ref_lat, -90.0..90.0);
+
+        if approx_eq!(f64, ref_lat.abs(), 90.0) {
+            return Err(ProjectionError::IncorrectParams(
+                "polar aspect is not supported, use PolarStereographic instead",
+            ));
+        }
+
+        if scale_factor <= 0.0 {
+            return Err(ProjectionError::IncorrectParams(
+                "scale factor must be positive",
+            ));
+        }
+
+        let phi_0 = ref_lat.to_radians();
+
+        let params = match self.variant {
+            StereographicVariant::Oblique => oblique_params(phi_0, scale_factor, ellps),
+            StereographicVariant::Snyder => {
+                let chi_1 = conformal_lat(phi_0, ellps);
+                let m_1 = phi_0.cos()
+                    / (ellps.E * phi_0.sin())
+                        .mul_add(-(ellps.E * phi_0.sin()), 1.0)
+                        .sqrt();
+
+                VariantParams::Snyder {
+                    sin_chi_1: chi_1.sin(),
+                    cos_chi_1: chi_1.cos(),
+                    two_a_k_0_m_1: 2.0 * ellps.A * scale_factor * m_1,
+                }
+            }
+        };
+
+        Ok(Stereographic {
+            lambda_0: ref_lon.to_radians(),
+            params,
+            ellps,
+        })
+    }
+}
+
+impl Projection for Stereographic {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let lambda = adjust_lon(lon - self.lambda_0.to_degrees()).to_radians();
+
+        match self.params {
+            VariantParams::Oblique {
+                n,
+                c,
+                sin_chi_0,
+                cos_chi_0,
+                two_r_k_0,
+                ..
+            } => {
+                // Formulas from EPSG Guidance Note 7-2, section 3.2.2.2
+                let s_a = (1.0 + phi.sin()) / (1.0 - phi.sin());
+                let s_b =
+                    self.ellps.E.mul_add(-phi.sin(), 1.0) / self.ellps.E.mul_add(phi.sin(), 1.0);
+                let w = c * (s_a * s_b.powf(self.ellps.E)).powf(n);
+                let chi = ((w - 1.0) / (w + 1.0)).asin();
+                let big_lambda = n * lambda;
+
+                let b = (chi.cos() * cos_chi_0)
+                    .mul_add(big_lambda.cos(), chi.sin().mul_add(sin_chi_0, 1.0));
+
+                let x = two_r_k_0 * chi.cos() * big_lambda.sin() / b;
+                let y = two_r_k_0
+                    * chi
+                        .sin()
+                        .mul_add(cos_chi_0, -(chi.cos() * sin_chi_0 * big_lambda.cos()))
+                    / b;
+
+                (x, y)
+            }
+            VariantParams::Snyder {
+                sin_chi_1,
+                cos_chi_1,
+                two_a_k_0_m_1,
+            } => {
+                // Formulas (21-24) to (21-27) from Snyder (1987)
+                let chi = conformal_lat(phi, self.ellps);
+
+                let big_a = two_a_k_0_m_1
+                    / (cos_chi_1
+                        * (cos_chi_1 * chi.cos())
+                            .mul_add(lambda.cos(), sin_chi_1.mul_add(chi.sin(), 1.0)));
+
+                let x = big_a * chi.cos() * lambda.sin();
+                let y =
+                    big_a * cos_chi_1.mul_add(chi.sin(), -(sin_chi_1 * chi.cos() * lambda.cos()));
+
+                (x, y)
+            }
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::many_single_char_names)]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let (lambda, t) = match self.params {
+            VariantParams::Oblique {
+                n,
+                c,
+                chi_0,
+                two_r_k_0,
+                ..
+            } => {
+                // Formulas from EPSG Guidance Note 7-2, section 3.2.2.2
+                let g = two_r_k_0 * 0.5f64.mul_add(-chi_0, FRAC_PI_4).tan();
+                let h = (2.0 * two_r_k_0).mul_add(chi_0.tan(), g);
+                let i = x.atan2(h + y);
+                let j = x.atan2(g - y) - i;
+
+                let chi =
+                    2.0f64.mul_add(((-x).mul_add((j / 2.0).tan(), y) / two_r_k_0).atan(), chi_0);
+                let big_lambda = 2.0f64.mul_add(i, j);
+
+                // Latitude on the conformal sphere is not equal to conformal latitude on the ellipsoid,
+                // so isometric latitude (ψ) needs to be rescaled before computing geodetic latitude
+                let psi = 0.5 * ((1.0 + chi.sin()) / (c * (1.0 - chi.sin()))).ln() / n;
+
+                (big_lambda / n, (-psi).exp())
+            }
+            VariantParams::Snyder {
+                sin_chi_1,
+                cos_chi_1,
+                two_a_k_0_m_1,
+            } => {
+                // Formulas (21-37) to (21-41) from Snyder (1987)
+                let rho = x.hypot(y);
+                let c_e = 2.0 * (rho * cos_chi_1 / two_a_k_0_m_1).atan();
+
+                let chi = if approx_eq!(f64, rho, 0.0) {
+                    sin_chi_1.asin()
+                } else {
+                    c_e.cos()
+                        .mul_add(sin_chi_1, y * c_e.sin() * cos_chi_1 / rho)
+                        .asin()
+                };
+
+                let lambda = (x * c_e.sin())
+                    .atan2((rho * cos_chi_1).mul_add(c_e.cos(), -(y * sin_chi_1 * c_e.sin())));
+
+                (lambda, 0.5f64.mul_add(-chi, FRAC_PI_4).tan())
+            }
+        };
+
+        let phi = phi_for_inverse(t, self.ellps);
+        let lambda = lambda + self.lambda_0;
+
+        (adjust_lon(lambda.to_degrees()), phi.to_degrees())
+    }
+}
+
+/// Conformal latitude, Snyder (1987) eq. (3-1).
+fn conformal_lat(phi: f64, ellps: Ellipsoid) -> f64 {
+    2.0f64.mul_add(-t(phi, ellps).atan(), FRAC_PI_2)
+}
+
+/// Computes constants of the conformal sphere, EPSG Guidance Note 7-2, section 3.2.2.2.
+fn oblique_params(phi_0: f64, k_0: f64, ellps: Ellipsoid) -> VariantParams {
+    let e2 = ellps.E * ellps.E;
+    let sin_phi_0 = phi_0.sin();
+    let cos_phi_0 = phi_0.cos();
+
+    let rho_0 = ellps.A * (1.0 - e2) / (e2 * sin_phi_0).mul_add(-sin_phi_0, 1.0).powf(1.5);
+    let nu_0 = ellps.A / (e2 * sin_phi_0).mul_add(-sin_phi_0, 1.0).sqrt();
+    let r = (rho_0 * nu_0).sqrt();
+
+    let n = (e2 * cos_phi_0.powi(4) / (1.0 - e2) + 1.0).sqrt();
+    let s_1 = (1.0 + sin_phi_0) / (1.0 - sin_phi_0);
+    let s_2 = ellps.E.mul_add(-sin_phi_0, 1.0) / ellps.E.mul_add(sin_phi_0, 1.0);
+    let w_1 = (s_1 * s_2.powf(ellps.E)).powf(n);
+    let sin_chi_00 = (w_1 - 1.0) / (w_1 + 1.0);
+    let c = (n + sin_phi_0) * (1.0 - sin_chi_00) / ((n - sin_phi_0) * (1.0 + sin_chi_00));
+    let w_2 = c * w_1;
+    let chi_0 = ((w_2 - 1.0) / (w_2 + 1.0)).asin();
+
+    VariantParams::Oblique {
+        n,
+        c,
+        sin_chi_0: chi_0.sin(),
+        cos_chi_0: chi_0.cos(),
+        chi_0,
+        two_r_k_0: 2.0 * r * k_0,
+    }
+}
@@ -0,0 +1,411 @@
+//! The transverse Mercator map projection is an adaptation of the standard Mercator projection.
+//! The transverse version is widely used in national and international mapping systems around the world,
+//! including the Universal Transverse Mercator. When paired with a suitable geodetic datum,
+//! the transverse Mercator delivers high accuracy in zones less than a few degrees in east-west extent
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Transverse_Mercator_projection).
+//!
+//! This implementation uses the Krüger series to the sixth order in third flattening
+//! as described by [C. F. F. Karney (2011)](https://doi.org/10.1007/s00190-011-0445-3),
+//! so its accuracy remains at the level of millimetres even several thousands kilometres
+//! away from the central meridian.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Cylindrical (transverse).
+//! - Conformal.
+//! - Central meridian, each meridian 90° from central meridian, and Equator are straight lines.
+//! - Other meridians and parallels are complex curves.
+//! - Scale is true along central meridian, or along two straight lines equidistant from and parallel to central meridian.
+//! - Scale becomes infinite on sphere 90° from central meridian.
+//! - Used extensively for quadrangle maps at scales from 1:24,000 to 1:250,000.
+//! - Used for the Universal Transverse Mercator (UTM) and many national grid systems.
+//! - Presented by Lambert in 1772.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{
+    ProjectionError, ensure_finite, ensure_within_range, unpack_required_parameter,
+};
+use crate::projections::Hemisphere;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::PI;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Scale factor on the central meridian used by all UTM zones.
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+
+/// False easting used by all UTM zones.
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+
+/// False northing used by UTM zones on the southern hemisphere.
+const UTM_SOUTH_FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// Maximum number of Newton iterations when computing latitude in the inverse projection.
+const MAX_ITERATIONS: usize = 10;
+
+/// Main projection struct that is constructed from [`TransverseMercatorBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct TransverseMercator {
+    lambda_0: f64,
+    k_0_a: f64,
+    m_0: f64,
+    false_easting: f64,
+    false_northing: f64,
+    alpha: [f64; 6],
+    beta: [f64; 6],
+    ellps: Ellipsoid,
+}
+
+impl TransverseMercator {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> TransverseMercatorBuilder {
+        TransverseMercatorBuilder::default()
+    }
+
+    /// Convenience constructor of Universal Transverse Mercator projection for
+    /// given zone and hemisphere on [`WGS84`](Ellipsoid::WGS84) ellipsoid.
+    ///
+    /// To use UTM zone on different ellipsoid use [`TransverseMercatorBuilder::utm_zone`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError::ParamOutOfRange`] when zone is not within 1..=60 range.
+    pub fn utm(zone: u8, hemisphere: Hemisphere) -> Result<Self, ProjectionError> {
+        Self::builder()
+            .utm_zone(zone, hemisphere)
+            .initialize_projection()
+    }
+}
+
+/// Returns the number of UTM zone containing provided longitude and latitude,
+/// including the exceptions for south-western Norway and Svalbard.
+///
+/// # Errors
+///
+/// Returns [`ProjectionError`] with additional information when:
+///
+/// - one or more arguments are not finite.
+/// - longitude is not within -180..180 range.
+/// - latitude is not within -80..84 range, which is covered by UTM.
+pub fn utm_zone(lon: f64, lat: f64) -> Result<u8, ProjectionError> {
+    ensure_finite!(lon, lat);
+    ensure_within_range!(lon, -180.0..180.0);
+    ensure_within_range!(lat, -80.0..84.0);
+
+    // Norway
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+        return Ok(32);
+    }
+
+    // Svalbard
+    if lat >= 72.0 && (0.0..42.0).contains(&lon) {
+        return Ok(match lon {
+            l if l < 9.0 => 31,
+            l if l < 21.0 => 33,
+            l if l < 33.0 => 35,
+            _ => 37,
+        });
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let zone = ((lon + 180.0) / 6.0).floor() as u8 + 1;
+
+    Ok(zone)
+}
+
+/// Builder struct which allows to construct [`TransverseMercator`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct TransverseMercatorBuilder {
+    ref_lon: Option<f64>,
+    ref_lat: Option<f64>,
+    scale_factor: f64,
+    false_easting: f64,
+    false_northing: f64,
+    utm_zone: Option<u8>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for TransverseMercatorBuilder {
+    fn default() -> Self {
+        Self {
+            ref_lon: None,
+            ref_lat: None,
+            scale_factor: 1.0,
+            false_easting: 0.0,
+            false_northing: 0.0,
+            utm_zone: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl TransverseMercatorBuilder {
+    /// *(required, alternative with [`utm_zone`](TransverseMercatorBuilder::utm_zone))* Sets reference longitude and latitude.
+    /// Reference longitude is the central meridian of the projection. Point (0, 0) on the map will be at this coordinates
+    /// (unless false easting and northing are set).
+    pub const fn ref_lonlat(&mut self, lon: f64, lat: f64) -> &mut Self {
+        self.ref_lon = Some(lon);
+        self.ref_lat = Some(lat);
+        self.utm_zone = None;
+        self
+    }
+
+    /// *(required, alternative with [`ref_lonlat`](TransverseMercatorBuilder::ref_lonlat))* Sets all parameters
+    /// (except the ellipsoid) to the values of Universal Transverse Mercator zone in given hemisphere.
+    /// Zone number is validated when the projection is initialized.
+    pub const fn utm_zone(&mut self, zone: u8, hemisphere: Hemisphere) -> &mut Self {
+        self.utm_zone = Some(zone);
+        self.ref_lat = Some(0.0);
+        self.scale_factor = UTM_SCALE_FACTOR;
+        self.false_easting = UTM_FALSE_EASTING;
+        self.false_northing = match hemisphere {
+            Hemisphere::North => 0.0,
+            Hemisphere::South => UTM_SOUTH_FALSE_NORTHING,
+        };
+        self
+    }
+
+    /// *(optional)* Sets scale factor on the central meridian, defaults to `1.0`.
+    pub const fn scale_factor(&mut self, k_0: f64) -> &mut Self {
+        self.scale_factor = k_0;
+        self
+    }
+
+    /// *(optional)* Sets false easting and false northing (in meters) added to projected coordinates, defaults to `(0.0, 0.0)`.
+    pub const fn false_origin(&mut self, easting: f64, northing: f64) -> &mut Self {
+        self.false_easting = easting;
+        self.false_northing = northing;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// TMERC projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - one or more longitudes are not within -180..180 range.
+    /// - one or more latitudes are not within -90..90 range.
+    /// - one or more arguments are not finite.
+    /// - UTM zone is not within 1..=60 range.
+    /// - scale factor is not positive.
+    pub fn initialize_projection(&self) -> Result<TransverseMercator, ProjectionError> {
+        let ref_lon = match self.utm_zone {
+            Some(zone) => {
+                let zone = f64::from(zone);
+                ensure_within_range!(zone, 1.0..61.0);
+                zone.mul_add(6.0, -183.0)
+            }
+            None => unpack_required_parameter!(self, ref_lon),
+        };
+        let ref_lat = unpack_required_parameter!(self, ref_lat);
+        let scale_factor = self.scale_factor;
+        let false_easting = self.false_easting;
+        let false_northing = self.false_northing;
+        let ellps = self.ellipsoid;
+        ensure_finite!(
+            ref_lon,
+            ref_lat,
+            scale_factor,
+            false_easting,
+            false_northing
+        );
+
+        ensure_within_range!(ref_lon, -180.0..180.0);
+        ensure_within_range!(ref_lat, -90.0..90.0);
+
+        if scale_factor <= 0.0 {
+            return Err(ProjectionError::IncorrectParams(
+                "scale factor must be positive",
+            ));
+        }
+
+        let n = ellps.F / (2.0 - ellps.F);
+        let alpha = alpha(n);
+        let beta = beta(n);
+
+        let k_0_a = scale_factor * rectifying_radius(ellps.A, n);
+        let m_0 = k_0_a
+            * forward_series(
+                conformal_tan(ref_lat.to_radians().tan(), ellps.E),
+                1.0,
+                0.0,
+                &alpha,
+            )
+            .0;
+
+        Ok(TransverseMercator {
+            lambda_0: ref_lon.to_radians(),
+            k_0_a,
+            m_0,
+            false_easting,
+            false_northing,
+            alpha,
+            beta,
+            ellps,
+        })
+    }
+}
+
+impl Projection for TransverseMercator {
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lambda(lon.to_radians() - self.lambda_0);
+        let tau_prime = conformal_tan(lat.to_radians().tan(), self.ellps.E);
+
+        let (xi, eta) = forward_series(tau_prime, lambda.cos(), lambda.sin(), &self.alpha);
+
+        let x = self.k_0_a.mul_add(eta, self.false_easting);
+        let y = self.k_0_a.mul_add(xi, -self.m_0) + self.false_northing;
+
+        (x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let xi = (y - self.false_northing + self.m_0) / self.k_0_a;
+        let eta = (x - self.false_easting) / self.k_0_a;
+
+        let mut xi_prime = xi;
+        let mut eta_prime = eta;
+        for (j, beta_j) in (1..).zip(self.beta) {
+            let j = f64::from(j) * 2.0;
+            xi_prime -= beta_j * (j * xi).sin() * (j * eta).cosh();
+            eta_prime -= beta_j * (j * xi).cos() * (j * eta).sinh();
+        }
+
+        let tau_prime = xi_prime.sin() / eta_prime.sinh().hypot(xi_prime.cos());
+        let lambda = eta_prime.sinh().atan2(xi_prime.cos());
+        let tau = geodetic_tan(tau_prime, self.ellps.E);
+
+        let lon = adjust_lon((lambda + self.lambda_0).to_degrees());
+        let lat = tau.atan().to_degrees();
+
+        (lon, lat)
+    }
+}
+
+/// Brings longitude difference to -π..π range, so that
+/// coordinates across the antimeridian are projected correctly.
+fn adjust_lambda(lambda: f64) -> f64 {
+    (lambda + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// Rectifying radius (A) from Karney (2011) eq. (14).
+pub(crate) fn rectifying_radius(a: f64, n: f64) -> f64 {
+    let n2 = n * n;
+    a / (1.0 + n) * n2.mul_add(n2.mul_add(n2.mul_add(1.0 / 256.0, 1.0 / 64.0), 0.25), 1.0)
+}
+
+/// Coefficients of the forward series from Karney (2011) eq. (35).
+fn alpha(n: f64) -> [f64; 6] {
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+
+    [
+        n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0 - 127.0 * n5 / 288.0
+            + 7891.0 * n6 / 37800.0,
+        13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0 + 281.0 * n5 / 630.0
+            - 1_983_433.0 * n6 / 1_935_360.0,
+        61.0 * n3 / 240.0 - 103.0 * n4 / 140.0
+            + 15061.0 * n5 / 26880.0
+            + 167_603.0 * n6 / 181_440.0,
+        49561.0 * n4 / 161_280.0 - 179.0 * n5 / 168.0 + 6_601_661.0 * n6 / 7_257_600.0,
+        34729.0 * n5 / 80640.0 - 3_418_889.0 * n6 / 1_995_840.0,
+        212_378_941.0 * n6 / 319_334_400.0,
+    ]
+}
+
+/// Coefficients of the inverse series from Karney (2011) eq. (36).
+fn beta(n: f64) -> [f64; 6] {
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+
+    [
+        n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0 - 81.0 * n5 / 512.0
+            + 96199.0 * n6 / 604_800.0,
+        n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0 + 46.0 * n5 / 105.0
+            - 1_118_711.0 * n6 / 3_870_720.0,
+        17.0 * n3 / 480.0 - 37.0 * n4 / 840.0 - 209.0 * n5 / 4480.0 + 5569.0 * n6 / 90720.0,
+        4397.0 * n4 / 161_280.0 - 11.0 * n5 / 504.0 - 830_251.0 * n6 / 7_257_600.0,
+        4583.0 * n5 / 161_280.0 - 108_847.0 * n6 / 3_991_680.0,
+        20_648_693.0 * n6 / 638_668_800.0,
+    ]
+}
+
+/// Computes normalised northing and easting (ξ, η) on the ellipsoid
+/// from tangent of conformal latitude and longitude difference,
+/// Karney (2011) eq. (10) and (11).
+fn forward_series(
+    tau_prime: f64,
+    cos_lambda: f64,
+    sin_lambda: f64,
+    alpha: &[f64; 6],
+) -> (f64, f64) {
+    let xi_prime = tau_prime.atan2(cos_lambda);
+    let eta_prime = (sin_lambda / tau_prime.hypot(cos_lambda)).asinh();
+
+    let mut xi = xi_prime;
+    let mut eta = eta_prime;
+    for (j, alpha_j) in (1..).zip(alpha) {
+        let j = f64::from(j) * 2.0;
+        xi += alpha_j * (j * xi_prime).sin() * (j * eta_prime).cosh();
+        eta += alpha_j * (j * xi_prime).cos() * (j * eta_prime).sinh();
+    }
+
+    (xi, eta)
+}
+
+/// Tangent of conformal latitude from tangent of geodetic latitude, Karney (2011) eq. (7) and (9).
+fn conformal_tan(tau: f64, e: f64) -> f64 {
+    let sigma = (e * (e * tau / tau.hypot(1.0)).atanh()).sinh();
+    tau.mul_add(sigma.hypot(1.0), -(sigma * tau.hypot(1.0)))
+}
+
+/// Tangent of geodetic latitude from tangent of conformal latitude,
+/// computed with Newton's method as in Karney (2011) eq. (19) - (21).
+fn geodetic_tan(tau_prime: f64, e: f64) -> f64 {
+    let e2m = e.mul_add(-e, 1.0);
+    let mut tau = tau_prime;
+
+    for _ in 0..MAX_ITERATIONS {
+        let tau_i_prime = conformal_tan(tau, e);
+        let d_tau = (tau_prime - tau_i_prime) * (e2m * tau).mul_add(tau, 1.0)
+            / (e2m * tau_i_prime.hypot(1.0) * tau.hypot(1.0));
+        tau += d_tau;
+
+        if d_tau.abs() <= f64::EPSILON * tau.abs().max(1.0) {
+            break;
+        }
+    }
+
+    tau
+}
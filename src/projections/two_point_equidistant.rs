@@ -0,0 +1,222 @@
+//! The two-point equidistant projection is a map projection in which distances from any point
+//! to two chosen control points are true. It is a generalization of the azimuthal equidistant
+//! projection, with two points instead of one, and is used for maps showing distances
+//! from two places, such as flight corridors between two airports
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Two-point_equidistant_projection).
+//!
+//! Control points are placed on the x axis, symmetrically about the origin, with the second point
+//! to the east. This projection uses Geodesic computation (defined by [C. F. F. Karney (2013)](https://doi.org/10.1007/s00190-012-0578-z))
+//! to compute distances from the control points, so distances are true also on the ellipsoid.
+//! Note that `tpeqd` projection from `Proj` is defined only for the sphere.
+//!
+//! Inverse projection is computed numerically and [`ProjectionError::InverseNotConverged`]
+//! is returned from checked [`inverse_project`](Projection::inverse_project) when it does not converge.
+//! Both directions might be slower than in most other projections.
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Modified azimuthal.
+//! - Neither equal-area nor conformal.
+//! - Distances from either of two chosen points to any other point are correct.
+//! - Used by the National Geographic Society for maps of Asia.
+//! - Presented by Maurer in 1919 and by Close in 1921.
+
+use crate::{
+    Ellipsoid, Projection, ProjectionError,
+    errors::{ensure_finite, ensure_within_range, unpack_required_parameter},
+    projections::{numerical_inverse, oblique_lon_lat::adjust_lon},
+};
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`TwoPointEquidistantBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct TwoPointEquidistant {
+    lon_1: f64,
+    lat_1: f64,
+    lon_2: f64,
+    lat_2: f64,
+    lon_0: f64,
+    azi_12: f64,
+    half_d: f64,
+    a: f64,
+    geod: Geodesic,
+}
+
+impl TwoPointEquidistant {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> TwoPointEquidistantBuilder {
+        TwoPointEquidistantBuilder::default()
+    }
+
+    /// Computes the inverse projection with Newton's method, starting from the point
+    /// at true distance from the first control point in the direction taken from the map.
+    /// Returns `None` when the iteration does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let d_1 = (x + self.half_d).hypot(y);
+        let azi = self.azi_12 - y.atan2(x + self.half_d).to_degrees();
+        let (lat, lon) = self.geod.direct(self.lat_1, self.lon_1, azi, d_1);
+
+        let (lambda, phi) = numerical_inverse::solve(
+            |lambda, phi| {
+                let (x, y) = self.project_unchecked(
+                    adjust_lon(self.lon_0 + lambda.to_degrees()),
+                    phi.to_degrees(),
+                );
+                (x / self.a, y / self.a)
+            },
+            x / self.a,
+            y / self.a,
+            (adjust_lon(lon - self.lon_0).to_radians(), lat.to_radians()),
+        )?;
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+}
+
+/// Builder struct which allows to construct [`TwoPointEquidistant`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct TwoPointEquidistantBuilder {
+    points: Option<(f64, f64, f64, f64)>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for TwoPointEquidistantBuilder {
+    fn default() -> Self {
+        Self {
+            points: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl TwoPointEquidistantBuilder {
+    /// *(required)* Sets longitudes and latitudes of two control points, from which distances are true.
+    /// On the map, the first point is placed at `(-d / 2, 0)` and the second one at `(d / 2, 0)`,
+    /// where `d` is the geodesic distance between them.
+    pub const fn control_points(
+        &mut self,
+        lon_1: f64,
+        lat_1: f64,
+        lon_2: f64,
+        lat_2: f64,
+    ) -> &mut Self {
+        self.points = Some((lon_1, lat_1, lon_2, lat_2));
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Two-Point Equidistant projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - one or more longitudes are not within -180..180 range.
+    /// - one or more latitudes are not within -90..=90 range.
+    /// - control points are at the same location.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<TwoPointEquidistant, ProjectionError> {
+        let (lon_1, lat_1, lon_2, lat_2) = unpack_required_parameter!(self, points);
+        let ellps = self.ellipsoid;
+        ensure_finite!(lon_1, lat_1, lon_2, lat_2);
+
+        ensure_within_range!(lon_1, -180.0..180.0);
+        ensure_within_range!(lon_2, -180.0..180.0);
+
+        for (name, lat) in [("lat_1", lat_1), ("lat_2", lat_2)] {
+            if !(-90.0..=90.0).contains(&lat) {
+                return Err(ProjectionError::ParamOutOfRange(name, -90.0, 90.0));
+            }
+        }
+
+        let geod: Geodesic = ellps.into();
+        let (d, azi_12, _, _) = geod.inverse(lat_1, lon_1, lat_2, lon_2);
+
+        if d <= 0.0 {
+            return Err(ProjectionError::IncorrectParams(
+                "control points must be at different locations",
+            ));
+        }
+
+        Ok(TwoPointEquidistant {
+            lon_1,
+            lat_1,
+            lon_2,
+            lat_2,
+            lon_0: adjust_lon(lon_1 + adjust_lon(lon_2 - lon_1) / 2.0),
+            azi_12,
+            half_d: d / 2.0,
+            a: ellps.A,
+            geod,
+        })
+    }
+}
+
+impl Projection for TwoPointEquidistant {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the numerical inverse does not converge.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (d_1, azi_1, _, _) = self.geod.inverse(self.lat_1, self.lon_1, lat, lon);
+        let (d_2, _, _, _) = self.geod.inverse(self.lat_2, self.lon_2, lat, lon);
+
+        // The point is at the intersection of circles with radii d_1 and d_2 around the control points
+        let x = (d_1 - d_2) * (d_1 + d_2) / (4.0 * self.half_d);
+        let y = triangle_height(d_1, d_2, 2.0 * self.half_d);
+
+        // Points to the right of the geodesic from the first to the second point are below x axis
+        if (azi_1 - self.azi_12).to_radians().sin() > 0.0 {
+            (x, -y)
+        } else {
+            (x, y)
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
+
+/// Computes the height of the triangle with given sides over the base,
+/// from the area computed with numerically stable Heron's formula by Kahan.
+fn triangle_height(side_1: f64, side_2: f64, base: f64) -> f64 {
+    let mut sides = [side_1, side_2, base];
+    sides.sort_by(|a, b| b.total_cmp(a));
+    let [a, b, c] = sides;
+
+    let product = (a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c));
+
+    product.max(0.0).sqrt() / (2.0 * base)
+}
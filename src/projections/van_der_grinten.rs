@@ -0,0 +1,228 @@
+//! The van der Grinten projection is a compromise map projection, which means that it is neither
+//! equal-area nor conformal. Unlike perspective projections, the van der Grinten projection
+//! is an arbitrary geometric construction on the plane. Van der Grinten projects the entire Earth
+//! into a circle. It largely preserves the familiar shapes of the Mercator projection
+//! while modestly reducing Mercator's distortion [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Van_der_Grinten_projection).
+//!
+//! Its inverse involves solving a cubic equation, so here it is instead found with Newton's method
+//! starting from an approximate solution. When the iteration fails, which happens also
+//! for points outside of the circular map, checked [`inverse_project`](Projection::inverse_project)
+//! returns [`ProjectionError::InverseNotConverged`].
+//!
+//! Summary by [Snyder (1987)](https://pubs.er.usgs.gov/publication/pp1395):
+//!
+//! - Neither conformal nor equal-area.
+//! - Central meridian and Equator are straight lines, all other meridians and parallels are arcs of circles.
+//! - The world is shown within a circle.
+//! - Scale is true along the Equator.
+//! - Great distortion of area in polar regions.
+//! - Used for world maps, e.g. by the National Geographic Society from 1922 to 1988.
+//! - Presented by van der Grinten in 1898.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::numerical_inverse;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Tolerance for the special cases of the Equator, central meridian and the poles.
+const TOLERANCE: f64 = 1e-10;
+
+/// Number of bisection steps used for the initial latitude of the inverse projection.
+const BISECTION_STEPS: usize = 8;
+
+/// Main projection struct that is constructed from [`VanDerGrintenBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct VanDerGrinten {
+    lon_0: f64,
+    r: f64,
+}
+
+impl VanDerGrinten {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> VanDerGrintenBuilder {
+        VanDerGrintenBuilder::default()
+    }
+
+    /// Computes the inverse projection numerically, returns `None` when it does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let x = x / self.r;
+        let y = y / self.r;
+
+        let (lambda, phi) =
+            numerical_inverse::solve(unit_van_der_grinten, x, y, initial_guess(x, y))?;
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+}
+
+/// Builder struct which allows to construct [`VanDerGrinten`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct VanDerGrintenBuilder {
+    central_lon: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for VanDerGrintenBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl VanDerGrintenBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Van der Grinten projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<VanDerGrinten, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        Ok(VanDerGrinten {
+            lon_0: central_lon,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for VanDerGrinten {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the numerical inverse does not converge,
+    /// which also happens for points outside of the map.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let (x, y) = unit_van_der_grinten(lambda, lat.to_radians());
+
+        (self.r * x, self.r * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
+
+/// Computes coordinates of the van der Grinten projection on the unit sphere,
+/// formulas (29-1) to (29-6) from Snyder (1987).
+fn unit_van_der_grinten(lambda: f64, phi: f64) -> (f64, f64) {
+    let sin_theta = (phi / FRAC_PI_2).abs().min(1.0);
+
+    if phi.abs() <= TOLERANCE {
+        return (lambda, 0.0);
+    }
+
+    if lambda.abs() <= TOLERANCE || (sin_theta - 1.0).abs() < TOLERANCE {
+        return (0.0, (PI * (sin_theta.asin() / 2.0).tan()).copysign(phi));
+    }
+
+    let a = 0.5 * (PI / lambda - lambda / PI).abs();
+    let a2 = a * a;
+    let cos_theta = sin_theta.mul_add(-sin_theta, 1.0).sqrt();
+    let g = cos_theta / (sin_theta + cos_theta - 1.0);
+    let g2 = g * g;
+    let p = g * (2.0 / sin_theta - 1.0);
+    let p2 = p * p;
+
+    let g_p2 = g - p2;
+    let p2_a2 = p2 + a2;
+    let sqrt_d = (a2 * g_p2).mul_add(g_p2, -(p2_a2 * (g2 - p2))).sqrt();
+
+    // Formula (29-1) is rearranged to avoid cancellation when G - P² is negative
+    let x_abs = if g_p2 >= 0.0 {
+        a.mul_add(g_p2, sqrt_d) / p2_a2
+    } else {
+        (p2 - g2) / (sqrt_d - a * g_p2)
+    };
+
+    // Formula (29-2) expressed with x
+    let y_abs = x_abs
+        .mul_add(-(2.0f64.mul_add(a, x_abs)), 1.0)
+        .max(0.0)
+        .sqrt();
+
+    ((PI * x_abs).copysign(lambda), (PI * y_abs).copysign(phi))
+}
+
+/// Computes the starting point of the numerical inverse on the unit sphere.
+///
+/// Meridians are circular arcs through both poles, so longitude is found exactly
+/// from the circle passing through the point. Newton's method is unstable near
+/// the poles at the edge of the map, so latitude is first bracketed by bisection
+/// along that meridian, on which y increases monotonically with latitude.
+fn initial_guess(x: f64, y: f64) -> (f64, f64) {
+    let s = y.mul_add(y, x.mul_add(x, -PI * PI));
+    let lambda = if x.abs() <= TOLERANCE {
+        0.0
+    } else {
+        (s + s.hypot(2.0 * PI * x)) / (2.0 * x)
+    };
+
+    if y.abs() <= TOLERANCE {
+        return (lambda, 0.0);
+    }
+
+    let mut lower = 0.0;
+    let mut upper = FRAC_PI_2;
+
+    for _ in 0..BISECTION_STEPS {
+        let phi = 0.5 * (lower + upper);
+
+        if unit_van_der_grinten(lambda, phi).1 < y.abs() {
+            lower = phi;
+        } else {
+            upper = phi;
+        }
+    }
+
+    (lambda, (0.5 * (lower + upper)).copysign(y))
+}
@@ -0,0 +1,174 @@
+//! The Winkel tripel projection (Winkel III) is a modified azimuthal map projection of the world,
+//! one of three projections proposed by German cartographer Oswald Winkel in 1921.
+//! The projection is the arithmetic mean of the equirectangular projection and the Aitoff projection.
+//! It was adopted by the National Geographic Society for its world maps in 1998
+//! [(Wikipedia, 2022)](https://en.wikipedia.org/wiki/Winkel_tripel_projection).
+//!
+//! The projection has no closed-form inverse, so the inverse is computed numerically
+//! and returns [`ProjectionError::InverseNotConverged`] from checked
+//! [`inverse_project`](Projection::inverse_project) when it does not converge.
+//!
+//! Summary by [Snyder (1993)](https://press.uchicago.edu/ucp/books/book/chicago/F/bo3632853.html):
+//!
+//! - Modified azimuthal.
+//! - Neither conformal nor equal-area.
+//! - Central meridian and Equator are straight lines, other meridians and parallels are curves.
+//! - Poles are straight lines about 0.4 as long as the Equator, depending on the standard parallel.
+//! - Used for world maps.
+//! - Presented by Winkel in 1921.
+//! - Used only in spherical form.
+
+use crate::Projection;
+use crate::ellipsoids::Ellipsoid;
+use crate::errors::{ProjectionError, ensure_finite, ensure_within_range};
+use crate::projections::aitoff::unit_aitoff;
+use crate::projections::numerical_inverse;
+use crate::projections::oblique_lon_lat::adjust_lon;
+use std::f64::consts::FRAC_2_PI;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Main projection struct that is constructed from [`WinkelTripelBuilder`] and used for computations.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct WinkelTripel {
+    lon_0: f64,
+    cos_phi_1: f64,
+    r: f64,
+}
+
+impl WinkelTripel {
+    /// Initializes builder with default values.
+    /// Projection parameters can be set with builder methods,
+    /// refer to the documentation of those methods to check which parmeters are required
+    /// and default values for optional arguments.
+    #[must_use]
+    pub fn builder() -> WinkelTripelBuilder {
+        WinkelTripelBuilder::default()
+    }
+
+    /// Computes the inverse projection numerically, returns `None` when it does not converge.
+    fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let x = x / self.r;
+        let y = y / self.r;
+        let (lambda, phi) =
+            numerical_inverse::solve(|lambda, phi| self.forward(lambda, phi), x, y, (x, y))?;
+
+        Some((
+            adjust_lon(self.lon_0 + lambda.to_degrees()),
+            phi.to_degrees(),
+        ))
+    }
+
+    /// Computes coordinates on the unit sphere as the mean of
+    /// Aitoff and equirectangular projections.
+    fn forward(&self, lambda: f64, phi: f64) -> (f64, f64) {
+        let (x, y) = unit_aitoff(lambda, phi);
+
+        (0.5 * lambda.mul_add(self.cos_phi_1, x), 0.5 * (y + phi))
+    }
+}
+
+/// Builder struct which allows to construct [`WinkelTripel`] projection.
+/// Refer to the documentation of this struct's methods to check which parmeters are required
+/// and default values for optional arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct WinkelTripelBuilder {
+    central_lon: f64,
+    std_parallel: Option<f64>,
+    ellipsoid: Ellipsoid,
+}
+
+impl Default for WinkelTripelBuilder {
+    fn default() -> Self {
+        Self {
+            central_lon: 0.0,
+            std_parallel: None,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+}
+
+impl WinkelTripelBuilder {
+    /// *(optional)* Sets the central meridian longitude, defaults to `0.0`.
+    pub const fn central_lon(&mut self, lon: f64) -> &mut Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// *(optional)* Sets standard parallel (latitude) of the equirectangular component,
+    /// defaults to `acos(2/π)` (50°28') used by Winkel.
+    pub const fn standard_parallel(&mut self, std_parallel: f64) -> &mut Self {
+        self.std_parallel = Some(std_parallel);
+        self
+    }
+
+    /// *(optional)* Sets reference [`Ellipsoid`], defaults to [`WGS84`](Ellipsoid::WGS84).
+    /// As the projection is only defined for sphere, the semi-major axis is used as its radius.
+    pub const fn ellipsoid(&mut self, ellps: Ellipsoid) -> &mut Self {
+        self.ellipsoid = ellps;
+        self
+    }
+
+    /// Winkel Tripel projection constructor.
+    ///
+    /// To reduce computational overhead of projection functions this
+    /// constructor is non-trivial and tries to do as much projection computations as possible.
+    /// Thus creating a new structure can involve a significant computational overhead.
+    /// When projecting multiple coordinates only one instance of the structure should be created
+    /// and copied/borrowed as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectionError`] with additional information when:
+    ///
+    /// - central longitude is not within -180..180 range.
+    /// - standard parallel is not within -90..90 range.
+    /// - one or more arguments are not finite.
+    pub fn initialize_projection(&self) -> Result<WinkelTripel, ProjectionError> {
+        let central_lon = self.central_lon;
+        ensure_finite!(central_lon);
+        ensure_within_range!(central_lon, -180.0..180.0);
+
+        let cos_phi_1 = if let Some(std_par) = self.std_parallel {
+            ensure_finite!(std_par);
+            ensure_within_range!(std_par, -90.0..90.0);
+
+            std_par.to_radians().cos()
+        } else {
+            FRAC_2_PI
+        };
+
+        Ok(WinkelTripel {
+            lon_0: central_lon,
+            cos_phi_1,
+            r: self.ellipsoid.A,
+        })
+    }
+}
+
+impl Projection for WinkelTripel {
+    /// Same as the default [`Projection::inverse_project()`] but additionally returns
+    /// [`ProjectionError::InverseNotConverged`] when the numerical inverse does not converge,
+    /// which also happens for points outside of the map.
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        numerical_inverse::checked(self.inverse(x, y), x, y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = adjust_lon(lon - self.lon_0).to_radians();
+        let (x, y) = self.forward(lambda, lat.to_radians());
+
+        (self.r * x, self.r * y)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", instrument(level = "trace"))]
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        numerical_inverse::unchecked(self.inverse(x, y))
+    }
+}
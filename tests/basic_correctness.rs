@@ -3,7 +3,15 @@ mod special_cases;
 use float_cmp::assert_approx_eq;
 use mappers::{
     Ellipsoid, Projection,
-    projections::{AzimuthalEquidistant, LambertConformalConic},
+    projections::{
+        Aitoff, AlbersEqualAreaConic, AzimuthalEquidistant, Bonne, CassiniSoldner,
+        CylindricalEqualArea, EqualEarth, EquidistantConic, Hammer, Healpix, Hemisphere,
+        HotineObliqueMercator, LambertAzimuthalEqualArea, LambertConformalConic, Mercator,
+        MillerCylindrical, ModifiedAzimuthalEquidistant, Mollweide, PolarStereographic, Polyconic,
+        RHealpix, Robinson, Sinusoidal, Werner, WinkelTripel,
+        hotine_oblique_mercator::HotineVariant,
+        modified_azimuthal_equidistant::ModifiedAzimuthalEquidistantVariant,
+    },
 };
 use proj::Proj;
 
@@ -11,6 +19,7 @@ use proj::Proj;
 pub enum TestExtent {
     Global,
     Local,
+    Antimeridian,
 }
 
 static ELLIPSOIDS_TEST_SET: [(Ellipsoid, &str); 6] = [
@@ -44,6 +53,19 @@ static LOCAL_GEO_POINTS: [(f64, f64); 8] = [
     (32.85, 27.42),
 ];
 
+// Points more than 180° from the central meridian at 29°,
+// which must be projected across the antimeridian
+static ANTIMERIDIAN_GEO_POINTS: [(f64, f64); 8] = [
+    (-175.0, 30.0),
+    (-175.0, -30.0),
+    (-170.0, 45.0),
+    (-170.0, -45.0),
+    (-165.0, 15.0),
+    (-165.0, -15.0),
+    (-160.0, 60.0),
+    (-160.0, -60.0),
+];
+
 static MAP_POINTS: [(f64, f64); 8] = [
     (100_000.0, 100_000.0),
     (-100_000.0, 100_000.0),
@@ -73,6 +95,20 @@ macro_rules! basic_correctness_test {
             );
         }
     };
+
+    ($builder:ident,$projstr:expr,$extra_extent:ident) => {
+        basic_correctness_test!($builder, $projstr);
+
+        for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+            let int_proj = $builder.ellipsoid(ellps).initialize_projection().unwrap();
+
+            test_points_with_proj(
+                &int_proj,
+                &format!("{} +ellps={}", $projstr, ellps_name),
+                TestExtent::$extra_extent,
+            );
+        }
+    };
 }
 
 #[test]
@@ -84,6 +120,41 @@ fn azimuthal_equidistant() {
     basic_correctness_test!(partial_builder, partial_proj);
 }
 
+#[test]
+fn guam() {
+    // Proj falls back to the spherical azimuthal equidistant projection for the sphere
+    for (ellps, ellps_name) in &ELLIPSOIDS_TEST_SET[..5] {
+        let int_proj = ModifiedAzimuthalEquidistant::builder()
+            .ref_lonlat(29.0, 31.0)
+            .variant(ModifiedAzimuthalEquidistantVariant::Guam)
+            .ellipsoid(*ellps)
+            .initialize_projection()
+            .unwrap();
+        let proj_str = format!("+proj=aeqd +guam +lon_0=29.0 +lat_0=31.0 +ellps={ellps_name}");
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Global);
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+#[test]
+fn cassini_soldner() {
+    let mut partial_builder = CassiniSoldner::builder();
+    partial_builder.ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=cass +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn polyconic() {
+    let mut partial_builder = Polyconic::builder();
+    partial_builder.ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=poly +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
 #[test]
 fn lambert_conformal_conic() {
     let mut partial_builder = LambertConformalConic::builder();
@@ -104,6 +175,357 @@ fn lcc_single_par() {
     basic_correctness_test!(partial_builder, partial_proj);
 }
 
+#[test]
+fn hotine_oblique_mercator() {
+    let mut partial_builder = HotineObliqueMercator::builder();
+    partial_builder
+        .center(29.0, 31.0)
+        .azimuth(30.0)
+        .rectified_grid_angle(20.0)
+        .scale_factor(0.9996);
+    let partial_proj = "+proj=omerc +lonc=29.0 +lat_0=31.0 +alpha=30.0 +gamma=20.0 +k=0.9996";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn hom_variant_a() {
+    let mut partial_builder = HotineObliqueMercator::builder();
+    partial_builder
+        .center(29.0, 31.0)
+        .azimuth(30.0)
+        .variant(HotineVariant::A);
+    let partial_proj = "+proj=omerc +lonc=29.0 +lat_0=31.0 +alpha=30.0 +no_off";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn hom_two_points() {
+    let mut partial_builder = HotineObliqueMercator::builder();
+    partial_builder
+        .center(29.0, 31.0)
+        .two_points(20.0, 25.0, 40.0, 37.0);
+    let partial_proj = "+proj=omerc +lat_0=31.0 +lon_1=20.0 +lat_1=25.0 +lon_2=40.0 +lat_2=37.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn krovak() {
+    special_cases::krovak::basic_correctness();
+}
+
+#[test]
+fn mercator() {
+    let mut partial_builder = Mercator::builder();
+    partial_builder.central_lon(29.0).scale_factor(0.9);
+    let partial_proj = "+proj=merc +lon_0=29.0 +k=0.9";
+
+    basic_correctness_test!(partial_builder, partial_proj, Antimeridian);
+}
+
+#[test]
+fn mercator_std_par() {
+    let mut partial_builder = Mercator::builder();
+    partial_builder.central_lon(29.0).standard_parallel(40.0);
+    let partial_proj = "+proj=merc +lon_0=29.0 +lat_ts=40.0";
+
+    basic_correctness_test!(partial_builder, partial_proj, Antimeridian);
+}
+
+#[test]
+fn cylindrical_equal_area() {
+    let mut partial_builder = CylindricalEqualArea::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=cea +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn cea_std_par() {
+    let mut partial_builder = CylindricalEqualArea::builder();
+    partial_builder.central_lon(29.0).standard_parallel(30.0);
+    let partial_proj = "+proj=cea +lon_0=29.0 +lat_ts=30.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn miller_cylindrical() {
+    let mut partial_builder = MillerCylindrical::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=mill +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn web_mercator() {
+    special_cases::mercator::basic_correctness();
+}
+
+#[test]
+fn polar_stereographic() {
+    let mut partial_builder = PolarStereographic::builder();
+    partial_builder
+        .straight_vertical_lon(29.0)
+        .true_scale_lat(60.0);
+    let partial_proj = "+proj=stere +lat_0=90.0 +lon_0=29.0 +lat_ts=60.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn polar_stereographic_south() {
+    let mut partial_builder = PolarStereographic::builder();
+    partial_builder
+        .pole(Hemisphere::South)
+        .straight_vertical_lon(29.0)
+        .scale_factor(0.994);
+    let partial_proj = "+proj=stere +lat_0=-90.0 +lon_0=29.0 +k=0.994";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn stereographic() {
+    special_cases::stereographic::basic_correctness();
+}
+
+#[test]
+fn transverse_mercator() {
+    special_cases::transverse_mercator::basic_correctness();
+}
+
+#[test]
+fn utm() {
+    special_cases::transverse_mercator::utm_correctness();
+}
+
+#[test]
+fn lambert_azimuthal_equal_area() {
+    let mut partial_builder = LambertAzimuthalEqualArea::builder();
+    partial_builder.ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=laea +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn laea_equatorial() {
+    let mut partial_builder = LambertAzimuthalEqualArea::builder();
+    partial_builder.ref_lonlat(29.0, 0.0);
+    let partial_proj = "+proj=laea +lon_0=29.0 +lat_0=0.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn laea_north_polar() {
+    let mut partial_builder = LambertAzimuthalEqualArea::builder();
+    partial_builder.ref_lonlat(29.0, 90.0);
+    let partial_proj = "+proj=laea +lon_0=29.0 +lat_0=90.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn laea_south_polar() {
+    let mut partial_builder = LambertAzimuthalEqualArea::builder();
+    partial_builder.ref_lonlat(29.0, -90.0);
+    let partial_proj = "+proj=laea +lon_0=29.0 +lat_0=-90.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn albers_equal_area_conic() {
+    let mut partial_builder = AlbersEqualAreaConic::builder();
+    partial_builder
+        .standard_parallels(30.0, 60.0)
+        .ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=aea +lat_1=30.0 +lat_2=60.0 +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj, Antimeridian);
+}
+
+#[test]
+fn aea_single_par() {
+    let mut partial_builder = AlbersEqualAreaConic::builder();
+    partial_builder.single_parallel(40.0).ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=aea +lat_1=40.0 +lat_2=40.0 +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj, Antimeridian);
+}
+
+#[test]
+fn equidistant_conic() {
+    let mut partial_builder = EquidistantConic::builder();
+    partial_builder
+        .standard_parallels(30.0, 60.0)
+        .ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=eqdc +lat_1=30.0 +lat_2=60.0 +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj, Antimeridian);
+}
+
+#[test]
+fn eqdc_single_par() {
+    let mut partial_builder = EquidistantConic::builder();
+    partial_builder.single_parallel(40.0).ref_lonlat(29.0, 31.0);
+    let partial_proj = "+proj=eqdc +lat_1=40.0 +lat_2=40.0 +lon_0=29.0 +lat_0=31.0";
+
+    basic_correctness_test!(partial_builder, partial_proj, Antimeridian);
+}
+
+#[test]
+fn bonne() {
+    let mut partial_builder = Bonne::builder();
+    partial_builder.single_parallel(30.0).central_lon(29.0);
+    let partial_proj = "+proj=bonne +lat_1=30.0 +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn bonne_south() {
+    let mut partial_builder = Bonne::builder();
+    partial_builder.single_parallel(-40.0).central_lon(29.0);
+    let partial_proj = "+proj=bonne +lat_1=-40.0 +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn werner() {
+    let mut partial_builder = Werner::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=bonne +lat_1=90.0 +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn robinson() {
+    let mut partial_builder = Robinson::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=robin +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn mollweide() {
+    let mut partial_builder = Mollweide::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=moll +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn goode_homolosine() {
+    special_cases::goode_homolosine::basic_correctness();
+}
+
+#[test]
+fn interrupted_goode_homolosine() {
+    special_cases::goode_homolosine::interrupted_correctness();
+}
+
+#[test]
+fn sinusoidal() {
+    let mut partial_builder = Sinusoidal::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=sinu +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn equal_earth() {
+    let mut partial_builder = EqualEarth::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=eqearth +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn aitoff() {
+    let mut partial_builder = Aitoff::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=aitoff +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn winkel_tripel() {
+    let mut partial_builder = WinkelTripel::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=wintri +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn healpix() {
+    let mut partial_builder = Healpix::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=healpix +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn rhealpix() {
+    let mut partial_builder = RHealpix::builder();
+    partial_builder
+        .central_lon(29.0)
+        .north_square(1)
+        .south_square(2);
+    let partial_proj = "+proj=rhealpix +lon_0=29.0 +north_square=1 +south_square=2";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn hammer() {
+    let mut partial_builder = Hammer::builder();
+    partial_builder.central_lon(29.0);
+    let partial_proj = "+proj=hammer +lon_0=29.0";
+
+    basic_correctness_test!(partial_builder, partial_proj);
+}
+
+#[test]
+fn van_der_grinten() {
+    special_cases::van_der_grinten::basic_correctness();
+}
+
+#[test]
+fn orthographic() {
+    special_cases::orthographic::basic_correctness();
+}
+
+#[test]
+fn near_sided_perspective() {
+    special_cases::near_sided_perspective::basic_correctness();
+}
+
+#[test]
+fn geostationary() {
+    special_cases::geostationary::basic_correctness();
+}
+
+#[test]
+fn gnomonic() {
+    special_cases::gnomonic::basic_correctness();
+}
+
 #[test]
 fn modified_azimuthal_equidistant() {
     special_cases::modified_azimuthal_equidistant::basic_correctness();
@@ -119,12 +541,23 @@ fn oblique_lon_lat() {
     special_cases::oblique_lon_lat::basic_correctness();
 }
 
+#[test]
+fn two_point_equidistant() {
+    special_cases::two_point_equidistant::basic_correctness();
+}
+
+#[test]
+fn oblique_lon_lat_geocentric() {
+    special_cases::oblique_lon_lat::geocentric_correctness();
+}
+
 pub fn test_points_with_proj<P: Projection>(int_proj: &P, proj_str: &str, extent: TestExtent) {
     let ref_proj = Proj::new(proj_str).unwrap();
 
     let geo_points = match extent {
         TestExtent::Global => GLOBAL_GEO_POINTS,
         TestExtent::Local => LOCAL_GEO_POINTS,
+        TestExtent::Antimeridian => ANTIMERIDIAN_GEO_POINTS,
     };
 
     for point in geo_points {
@@ -136,6 +569,14 @@ pub fn test_points_with_proj<P: Projection>(int_proj: &P, proj_str: &str, extent
 
         assert_approx_eq!(f64, ref_x, tst_x, epsilon = 0.000_000_1);
         assert_approx_eq!(f64, ref_y, tst_y, epsilon = 0.000_000_1);
+
+        // Inverse projected longitudes must be wrapped back to the same side of the antimeridian
+        if let TestExtent::Antimeridian = extent {
+            let (tst_lon, tst_lat) = int_proj.inverse_project(ref_x, ref_y).unwrap();
+
+            assert_approx_eq!(f64, point.0, tst_lon, epsilon = 0.000_000_1);
+            assert_approx_eq!(f64, point.1, tst_lat, epsilon = 0.000_000_1);
+        }
     }
 
     for point in MAP_POINTS {
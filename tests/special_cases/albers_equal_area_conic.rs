@@ -0,0 +1,51 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::AlbersEqualAreaConic};
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from Snyder (1987) p. 292-294
+    let proj = AlbersEqualAreaConic::builder()
+        .ref_lonlat(-96.0, 23.0)
+        .standard_parallels(29.5, 45.5)
+        .ellipsoid(Ellipsoid::CLARKE1866)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-75.0, 35.0).unwrap();
+
+    assert_approx_eq!(f64, x, 1_885_472.7, epsilon = 0.1);
+    assert_approx_eq!(f64, y, 1_535_925.0, epsilon = 0.1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -75.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 35.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let mut partial_builder = AlbersEqualAreaConic::builder();
+    partial_builder.ref_lonlat(2., 0.);
+
+    for std_par_1 in 1..90 {
+        let std_par_2 = -std_par_1;
+        let aea = partial_builder
+            .standard_parallels(std_par_1 as f64, std_par_2 as f64)
+            .initialize_projection()
+            .unwrap_err();
+        assert!(std::matches!(aea, ProjectionError::IncorrectParams { .. }));
+    }
+
+    for std_par_1 in (-80..80).step_by(10) {
+        for std_par_2 in (-80..80).step_by(10) {
+            if std_par_1 == -std_par_2 {
+                continue;
+            }
+
+            let aea = partial_builder
+                .standard_parallels(std_par_1 as f64, std_par_2 as f64)
+                .initialize_projection();
+            assert!(aea.is_ok());
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, projections::AzimuthalEquidistant};
+
+#[test]
+fn test_spherical_formulas() {
+    // Almost spherical ellipsoid is projected with geodesics
+    let near_sphere = Ellipsoid::new(Ellipsoid::SPHERE.A, 1e15);
+
+    let sph_proj = AzimuthalEquidistant::builder()
+        .ref_lonlat(29.0, 31.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let geod_proj = AzimuthalEquidistant::builder()
+        .ref_lonlat(29.0, 31.0)
+        .ellipsoid(near_sphere)
+        .initialize_projection()
+        .unwrap();
+
+    for (lon, lat) in [
+        (29.0, 31.0),
+        (29.0, 89.0),
+        (-151.0, 10.0),
+        (100.0, -60.0),
+        (-45.0, 45.0),
+        (179.0, -30.0),
+    ] {
+        let (x, y) = sph_proj.project(lon, lat).unwrap();
+        let (ref_x, ref_y) = geod_proj.project(lon, lat).unwrap();
+
+        assert_approx_eq!(f64, x, ref_x, epsilon = 0.000_1);
+        assert_approx_eq!(f64, y, ref_y, epsilon = 0.000_1);
+
+        let (tst_lon, tst_lat) = sph_proj.inverse_project(x, y).unwrap();
+
+        assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_1);
+        assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_1);
+    }
+}
+
+#[test]
+fn test_spherical_domain() {
+    let proj = AzimuthalEquidistant::builder()
+        .ref_lonlat(29.0, 31.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    // Antipode of the centre is a circle on the map
+    assert!(proj.project(-151.0, -31.0).is_err());
+
+    let (lon, lat) = proj.inverse_project(0.0, 0.0).unwrap();
+    assert_approx_eq!(f64, lon, 29.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 31.0, epsilon = 0.000_000_1);
+
+    let radius = Ellipsoid::SPHERE.A * std::f64::consts::PI;
+    assert!(proj.inverse_project(radius * 1.01, 0.0).is_err());
+}
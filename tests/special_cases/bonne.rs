@@ -0,0 +1,86 @@
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{Bonne, Werner},
+};
+
+#[test]
+fn test_werner() {
+    let werner = Werner::builder()
+        .central_lon(29.0)
+        .initialize_projection()
+        .unwrap();
+
+    let bonne = Bonne::builder()
+        .central_lon(29.0)
+        .single_parallel(90.0)
+        .initialize_projection()
+        .unwrap();
+
+    // The North Pole is the centre of parallels at the origin of the map
+    let (x, y) = werner.project(100.0, 90.0).unwrap();
+    assert_approx_eq!(f64, x, 0.0, epsilon = 0.000_001);
+    assert_approx_eq!(f64, y, 0.0, epsilon = 0.000_001);
+
+    let (lon, lat) = werner.inverse_project(0.0, 0.0).unwrap();
+    assert_approx_eq!(f64, lon, 29.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 90.0, epsilon = 0.000_000_1);
+
+    for (lon, lat) in [(0.0, 0.0), (-150.0, 45.0), (150.0, -80.0)] {
+        let (x, y) = werner.project(lon, lat).unwrap();
+        let (ref_x, ref_y) = bonne.project(lon, lat).unwrap();
+
+        assert_approx_eq!(f64, x, ref_x, epsilon = 0.000_001);
+        assert_approx_eq!(f64, y, ref_y, epsilon = 0.000_001);
+    }
+}
+
+#[test]
+fn test_standard_parallel() {
+    let proj = Bonne::builder()
+        .central_lon(10.0)
+        .single_parallel(45.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(10.0, 45.0).unwrap();
+    assert_approx_eq!(f64, x, 0.0, epsilon = 0.000_001);
+    assert_approx_eq!(f64, y, 0.0, epsilon = 0.000_001);
+
+    // The standard parallel is an arc of the circle with radius R cot φ1 = R, and its length is true
+    let r = Ellipsoid::SPHERE.A;
+    let (x, y) = proj.project(40.0, 45.0).unwrap();
+
+    assert_approx_eq!(f64, x.hypot(r - y), r, epsilon = 0.000_001);
+    assert_approx_eq!(
+        f64,
+        r * x.atan2(r - y),
+        r * 45.0_f64.to_radians().cos() * 30.0_f64.to_radians(),
+        epsilon = 0.000_001
+    );
+}
+
+#[test]
+fn test_constructor() {
+    let proj = Bonne::builder()
+        .single_parallel(0.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = Bonne::builder()
+        .single_parallel(91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+
+    let proj = Bonne::builder().initialize_projection().unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamRequired(..)));
+
+    let proj = Werner::builder()
+        .central_lon(180.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+}
@@ -0,0 +1,56 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::CassiniSoldner};
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from Snyder (1987), p. 279
+    let proj = CassiniSoldner::builder()
+        .ref_lonlat(-75.0, 40.0)
+        .ellipsoid(Ellipsoid::CLARKE1866)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-73.0, 43.0).unwrap();
+
+    assert_approx_eq!(f64, x, 163_071.1, epsilon = 0.1);
+    assert_approx_eq!(f64, y, 335_127.6, epsilon = 0.1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -73.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 43.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_false_origin() {
+    let proj = CassiniSoldner::builder()
+        .ref_lonlat(-75.0, 40.0)
+        .false_origin(500_000.0, 100_000.0)
+        .ellipsoid(Ellipsoid::CLARKE1866)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-73.0, 43.0).unwrap();
+
+    assert_approx_eq!(f64, x, 663_071.1, epsilon = 0.1);
+    assert_approx_eq!(f64, y, 435_127.6, epsilon = 0.1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -73.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 43.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let err = CassiniSoldner::builder()
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamRequired { .. }));
+
+    let err = CassiniSoldner::builder()
+        .ref_lonlat(29.0, 91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange { .. }));
+}
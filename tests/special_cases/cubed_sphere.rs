@@ -0,0 +1,297 @@
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{
+        CubedSphere,
+        cubed_sphere::{CubedSphereVariant, FaceOrientation},
+    },
+};
+
+#[test]
+fn test_face_centers() {
+    let proj = CubedSphere::builder()
+        .central_lon(30.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let centers = [
+        (30.0, 0.0),
+        (120.0, 0.0),
+        (30.0, 90.0),
+        (-150.0, 0.0),
+        (-60.0, 0.0),
+        (30.0, -90.0),
+    ];
+
+    for (face, (lon, lat)) in (0..6).zip(centers) {
+        let (tst_face, x, y) = proj.project_to_face(lon, lat).unwrap();
+
+        assert_eq!(tst_face, face);
+        assert_approx_eq!(f64, x, 0.0, epsilon = 0.000_001);
+        assert_approx_eq!(f64, y, 0.0, epsilon = 0.000_001);
+    }
+}
+
+#[test]
+fn test_variants() {
+    let r = Ellipsoid::SPHERE.A;
+
+    let equiangular = CubedSphere::builder()
+        .variant(CubedSphereVariant::Equiangular)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let equidistant = CubedSphere::builder()
+        .variant(CubedSphereVariant::Equidistant)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    // Along the Equator and central meridians angles are equal to longitude and latitude
+    let (_, x, _) = equiangular.project_to_face(30.0, 0.0).unwrap();
+    assert_approx_eq!(f64, x, r * 30.0_f64.to_radians(), epsilon = 0.000_001);
+
+    let (_, _, y) = equiangular.project_to_face(0.0, 30.0).unwrap();
+    assert_approx_eq!(f64, y, r * 30.0_f64.to_radians(), epsilon = 0.000_001);
+
+    let (face, x, _) = equiangular.project_to_face(75.0, 0.0).unwrap();
+    assert_eq!(face, 1);
+    assert_approx_eq!(f64, x, -r * 15.0_f64.to_radians(), epsilon = 0.000_001);
+
+    let (_, x, _) = equidistant.project_to_face(30.0, 0.0).unwrap();
+    assert_approx_eq!(f64, x, r * 30.0_f64.to_radians().tan(), epsilon = 0.000_001);
+
+    // Edges of faces
+    let (_, x, y) = equiangular.project_to_face(45.0, 0.0).unwrap();
+    assert_approx_eq!(f64, x, r * 45.0_f64.to_radians(), epsilon = 0.000_001);
+    assert_approx_eq!(f64, y, 0.0, epsilon = 0.000_001);
+
+    let (_, x, _) = equidistant.project_to_face(45.0, 0.0).unwrap();
+    assert_approx_eq!(f64, x, r, epsilon = 0.000_001);
+}
+
+#[test]
+fn test_fv3_orientation() {
+    let proj = CubedSphere::builder()
+        .central_lon(-10.0)
+        .orientation(FaceOrientation::Fv3)
+        .initialize_projection()
+        .unwrap();
+
+    // Points north-east of centres of equatorial faces and on meridians through polar faces,
+    // with expected signs of coordinates (zero when the point is on the axis)
+    let points = [
+        ((-5.0, 3.0), (0, 1, 1)),
+        ((85.0, 3.0), (1, 1, 1)),
+        ((175.0, 3.0), (3, -1, 1)),
+        ((-95.0, 3.0), (4, -1, 1)),
+        ((170.0, 80.0), (2, 1, 0)),
+        ((-100.0, 80.0), (2, 0, 1)),
+        ((80.0, -80.0), (5, 1, 0)),
+        ((-10.0, -80.0), (5, 0, 1)),
+    ];
+
+    for ((lon, lat), (face, sign_x, sign_y)) in points {
+        let (tst_face, x, y) = proj.project_to_face(lon, lat).unwrap();
+        assert_eq!(tst_face, face);
+
+        for (coord, sign) in [(x, sign_x), (y, sign_y)] {
+            if sign == 0 {
+                assert_approx_eq!(f64, coord, 0.0, epsilon = 0.000_001);
+            } else {
+                assert!(coord * f64::from(sign) > 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fv3_mosaic() {
+    const N: u32 = 8;
+    type Contact = (u8, fn(u32) -> (u32, u32), u8, fn((u32, u32)) -> bool);
+
+    let proj = CubedSphere::builder()
+        .central_lon(-10.0)
+        .orientation(FaceOrientation::Fv3)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let east = |k| (N - 1, k);
+    let north = |k| (k, N - 1);
+    let is_west = |(i, _): (u32, u32)| i == 0;
+    let is_south = |(_, j): (u32, u32)| j == 0;
+
+    // Contacts between tiles of the FV3 mosaic: odd tiles connect to the next tile through
+    // their east edge, and even tiles through their north edge
+    let contacts: [Contact; 12] = [
+        (0, east, 1, is_west),
+        (0, north, 2, is_west),
+        (1, north, 2, is_south),
+        (1, east, 3, is_south),
+        (2, east, 3, is_west),
+        (2, north, 4, is_west),
+        (3, north, 4, is_south),
+        (3, east, 5, is_south),
+        (4, east, 5, is_west),
+        (4, north, 0, is_west),
+        (5, north, 0, is_south),
+        (5, east, 1, is_south),
+    ];
+
+    for (face_a, edge_a, face_b, on_edge_b) in contacts {
+        for k in 0..N {
+            let (i, j) = edge_a(k);
+            let center_a = proj.cell_center(face_a, i, j, N).unwrap();
+
+            let nearest = (0..N)
+                .flat_map(|i| (0..N).map(move |j| (i, j)))
+                .min_by(|&(i_1, j_1), &(i_2, j_2)| {
+                    let d_1 = distance(center_a, proj.cell_center(face_b, i_1, j_1, N).unwrap());
+                    let d_2 = distance(center_a, proj.cell_center(face_b, i_2, j_2, N).unwrap());
+                    d_1.total_cmp(&d_2)
+                })
+                .unwrap();
+
+            assert!(on_edge_b(nearest));
+        }
+    }
+}
+
+#[test]
+fn test_cell_center() {
+    let equiangular = CubedSphere::builder()
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let equidistant = CubedSphere::builder()
+        .variant(CubedSphereVariant::Equidistant)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let (lon, lat) = equiangular.cell_center(0, 1, 1, 2).unwrap();
+    assert_approx_eq!(f64, lon, 22.5, epsilon = 0.000_000_1);
+    assert_approx_eq!(
+        f64,
+        lat,
+        22.5_f64.to_radians().sin().atan().to_degrees(),
+        epsilon = 0.000_000_1
+    );
+
+    let (lon, lat) = equidistant.cell_center(0, 1, 1, 2).unwrap();
+    assert_approx_eq!(f64, lon, 0.5_f64.atan().to_degrees(), epsilon = 0.000_000_1);
+    assert_approx_eq!(
+        f64,
+        lat,
+        (0.5 / 1.25_f64.sqrt()).atan().to_degrees(),
+        epsilon = 0.000_000_1
+    );
+
+    // Centres of all cells are projected onto their faces
+    for proj in [equiangular, equidistant] {
+        for face in 0..6 {
+            for i in 0..4 {
+                for j in 0..4 {
+                    let (lon, lat) = proj.cell_center(face, i, j, 4).unwrap();
+                    let (tst_face, _, _) = proj.project_to_face(lon, lat).unwrap();
+
+                    assert_eq!(tst_face, face);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for variant in [
+        CubedSphereVariant::Equiangular,
+        CubedSphereVariant::Equidistant,
+    ] {
+        for orientation in [FaceOrientation::Aligned, FaceOrientation::Fv3] {
+            let proj = CubedSphere::builder()
+                .central_lon(-10.0)
+                .variant(variant)
+                .orientation(orientation)
+                .initialize_projection()
+                .unwrap();
+
+            for lon in (-180..180).step_by(15) {
+                for lat in (-75..=75).step_by(15) {
+                    let (lon, lat) = (f64::from(lon) + 0.5, f64::from(lat) + 0.5);
+
+                    let (face, x, y) = proj.project_to_face(lon, lat).unwrap();
+                    let (tst_lon, tst_lat) = proj.inverse_project_from_face(face, x, y).unwrap();
+
+                    assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_1);
+                    assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_1);
+
+                    let (x, y) = proj.project(lon, lat).unwrap();
+                    let (tst_lon, tst_lat) = proj.inverse_project(x, y).unwrap();
+
+                    assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_1);
+                    assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_1);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_outside_map() {
+    let proj = CubedSphere::builder().initialize_projection().unwrap();
+    let width = Ellipsoid::WGS84.A * std::f64::consts::FRAC_PI_2;
+
+    // Beside polar faces in the net of the cube
+    assert!(proj.inverse_project(width, width).is_err());
+    assert!(proj.inverse_project(-width, -width).is_err());
+
+    let err = proj.inverse_project_from_face(0, width, 0.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::OutOfDomain(..)));
+
+    let err = proj.inverse_project_from_face(6, 0.0, 0.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange(..)));
+
+    let err = proj.cell_center(6, 0, 0, 4).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange(..)));
+
+    let err = proj.cell_center(0, 4, 0, 4).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange(..)));
+
+    let err = proj.cell_center(0, 0, 0, 0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::IncorrectParams(..)));
+}
+
+#[test]
+fn test_constructor() {
+    let proj = CubedSphere::builder()
+        .central_lon(180.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+
+    let proj = CubedSphere::builder()
+        .central_lon(f64::NAN)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamNotFinite(..)));
+}
+
+/// Central angle between two points on the sphere.
+fn distance((lon_1, lat_1): (f64, f64), (lon_2, lat_2): (f64, f64)) -> f64 {
+    let (lon_1, lat_1, lon_2, lat_2) = (
+        lon_1.to_radians(),
+        lat_1.to_radians(),
+        lon_2.to_radians(),
+        lat_2.to_radians(),
+    );
+
+    let h = ((lat_2 - lat_1) / 2.0).sin().powi(2)
+        + lat_1.cos() * lat_2.cos() * ((lon_2 - lon_1) / 2.0).sin().powi(2);
+
+    2.0 * h.sqrt().asin()
+}
@@ -0,0 +1,50 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::CylindricalEqualArea};
+
+#[test]
+fn test_ease_grid_2() {
+    // EASE-Grid 2.0 global grid (EPSG:6933), the corner of the grid extent
+    // is at the antimeridian and latitude 85.0445664°
+    let proj = CylindricalEqualArea::builder()
+        .standard_parallel(30.0)
+        .ellipsoid(Ellipsoid::WGS84)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(180.0, 85.044_566_4).unwrap();
+
+    assert_approx_eq!(f64, x, 17_367_530.445, epsilon = 0.001);
+    assert_approx_eq!(f64, y, 7_314_540.830, epsilon = 0.001);
+
+    let (lon, lat) = proj.inverse_project(-x, -y).unwrap();
+
+    assert_approx_eq!(f64, lon, -180.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, -85.044_566_4, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_outside_map() {
+    let proj = CylindricalEqualArea::builder()
+        .standard_parallel(30.0)
+        .initialize_projection()
+        .unwrap();
+
+    let (_, y) = proj.project(0.0, 90.0).unwrap();
+    let (_, lat) = proj.inverse_project(0.0, y).unwrap();
+    assert_approx_eq!(f64, lat, 90.0, epsilon = 0.000_000_1);
+
+    let err = proj.inverse_project(0.0, 1.01 * y).unwrap_err();
+    assert!(std::matches!(
+        err,
+        ProjectionError::InverseProjectionImpossible { .. }
+    ));
+}
+
+#[test]
+fn test_constructor() {
+    let err = CylindricalEqualArea::builder()
+        .standard_parallel(90.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange { .. }));
+}
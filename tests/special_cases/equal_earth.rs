@@ -0,0 +1,27 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::EqualEarth};
+use std::f64::consts::PI;
+
+#[test]
+fn test_outside_map() {
+    let proj = EqualEarth::builder()
+        .central_lon(29.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    // Points on the Equator beyond the western edge of the map
+    let r = Ellipsoid::SPHERE.A;
+    let (x, _) = proj.project(-151.0, 0.0).unwrap();
+
+    for x in [x * 1.01, 1.5 * PI * r, -1.5 * PI * r] {
+        let err = proj.inverse_project(x, 1000.0).unwrap_err();
+        assert!(std::matches!(
+            err,
+            ProjectionError::InverseProjectionImpossible(..)
+        ));
+    }
+
+    let (lon, _) = proj.inverse_project(x * 0.99, 0.0).unwrap();
+    assert_approx_eq!(f64, lon, 29.0 - 0.99 * 180.0, epsilon = 0.000_000_1);
+}
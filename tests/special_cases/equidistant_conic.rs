@@ -0,0 +1,44 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::EquidistantConic};
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from Snyder (1987) p. 297-299
+    let proj = EquidistantConic::builder()
+        .ref_lonlat(-96.0, 23.0)
+        .standard_parallels(29.5, 45.5)
+        .ellipsoid(Ellipsoid::CLARKE1866)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-75.0, 35.0).unwrap();
+
+    assert_approx_eq!(f64, x, 1_885_051.9, epsilon = 0.1);
+    assert_approx_eq!(f64, y, 1_540_507.6, epsilon = 0.1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -75.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 35.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let mut partial_builder = EquidistantConic::builder();
+    partial_builder.ref_lonlat(2., 0.);
+
+    for std_par_1 in 1..90 {
+        let std_par_2 = -std_par_1;
+        let eqdc = partial_builder
+            .standard_parallels(std_par_1 as f64, std_par_2 as f64)
+            .initialize_projection()
+            .unwrap_err();
+        assert!(std::matches!(eqdc, ProjectionError::IncorrectParams { .. }));
+    }
+
+    let eqdc = partial_builder
+        .standard_parallels(30.0, 91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(eqdc, ProjectionError::ParamOutOfRange { .. }));
+}
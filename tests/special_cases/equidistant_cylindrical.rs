@@ -1,9 +1,11 @@
 use crate::TestExtent;
 use crate::test_points_with_proj;
-use mappers::projections::EquidistantCylindrical;
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, projections::EquidistantCylindrical};
 
 pub(crate) fn basic_correctness() {
-    // This projection supports only spherical ellipsoid so must be tested separately
+    // Proj uses only spherical formulas for this projection, so it is compared only on the sphere,
+    // and the ellipsoidal form is tested with the EPSG example below
 
     for ref_lon in (-30..30).step_by(10) {
         for ref_lat in (-30..30).step_by(10) {
@@ -24,3 +26,24 @@ pub(crate) fn basic_correctness() {
         }
     }
 }
+
+#[test]
+fn test_ellipsoidal_example() {
+    // Numerical example from EPSG Guidance Note 7-2 (WGS 84 / World Equidistant Cylindrical),
+    // which replaces comparison with Proj for ellipsoids
+    let proj = EquidistantCylindrical::builder()
+        .ref_lonlat(0.0, 0.0)
+        .ellipsoid(Ellipsoid::WGS84)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(10.0, 55.0).unwrap();
+
+    assert_approx_eq!(f64, x, 1_113_194.91, epsilon = 0.01);
+    assert_approx_eq!(f64, y, 6_097_230.31, epsilon = 0.01);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, 10.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 55.0, epsilon = 0.000_000_1);
+}
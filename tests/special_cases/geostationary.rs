@@ -0,0 +1,114 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{
+        Geostationary,
+        geostationary::{ScanUnits, SweepAxis},
+    },
+};
+
+pub(crate) fn basic_correctness() {
+    // Most of global test points are not visible from the satellite,
+    // so only local points are tested
+
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        for (sweep, sweep_name) in [(SweepAxis::X, "x"), (SweepAxis::Y, "y")] {
+            let int_proj = Geostationary::builder()
+                .satellite_height(35_786_023.0)
+                .sub_satellite_lon(29.0)
+                .sweep_axis(sweep)
+                .ellipsoid(ellps)
+                .initialize_projection()
+                .unwrap();
+
+            let proj_str = format!(
+                "+proj=geos +h=35786023.0 +lon_0=29.0 +sweep={} +ellps={}",
+                sweep_name, ellps_name
+            );
+
+            test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+        }
+    }
+}
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from GOES-R Product User Guide, Volume 3, Section 4.2.8
+    let proj = Geostationary::builder()
+        .satellite_height(35_786_023.0)
+        .sub_satellite_lon(-75.0)
+        .sweep_axis(SweepAxis::X)
+        .units(ScanUnits::Radians)
+        .ellipsoid(Ellipsoid::GRS80)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-84.690_932, 33.846_162).unwrap();
+
+    assert_approx_eq!(f64, x, -0.024_052, epsilon = 0.000_001);
+    assert_approx_eq!(f64, y, 0.095_340, epsilon = 0.000_001);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -84.690_932, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 33.846_162, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_units() {
+    let mut builder = Geostationary::builder();
+    builder.satellite_height(35_785_831.0);
+
+    let meters = builder
+        .units(ScanUnits::Meters)
+        .initialize_projection()
+        .unwrap();
+    let radians = builder
+        .units(ScanUnits::Radians)
+        .initialize_projection()
+        .unwrap();
+
+    let (x_m, y_m) = meters.project(10.0, 45.0).unwrap();
+    let (x_r, y_r) = radians.project(10.0, 45.0).unwrap();
+
+    assert_approx_eq!(f64, x_m, x_r * 35_785_831.0, epsilon = 0.000_001);
+    assert_approx_eq!(f64, y_m, y_r * 35_785_831.0, epsilon = 0.000_001);
+}
+
+#[test]
+fn test_off_disk() {
+    let proj = Geostationary::builder()
+        .satellite_height(35_785_831.0)
+        .units(ScanUnits::Radians)
+        .initialize_projection()
+        .unwrap();
+
+    // The Earth disk is about 8.7 degrees in radius when seen from the satellite
+    let err = proj.inverse_project(0.16, 0.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::OffDisk { .. }));
+
+    let err = proj.inverse_project(0.12, 0.12).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::OffDisk { .. }));
+
+    assert!(proj.inverse_project(0.14, 0.0).is_ok());
+
+    let err = proj.project(100.0, 0.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+}
+
+#[test]
+fn test_constructor() {
+    let err = Geostationary::builder()
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamRequired { .. }));
+
+    let err = Geostationary::builder()
+        .satellite_height(-1.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::IncorrectParams { .. }));
+}
@@ -0,0 +1,77 @@
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::Gnomonic};
+
+pub(crate) fn basic_correctness() {
+    // Only spherical form is compared with Proj and most of global test points
+    // are further than 90 degrees from the center, so only local points are tested
+
+    for (ref_lon, ref_lat) in [(29.0, 31.0), (29.0, 0.0), (29.0, 90.0)] {
+        let int_proj = Gnomonic::builder()
+            .ref_lonlat(ref_lon, ref_lat)
+            .ellipsoid(Ellipsoid::SPHERE)
+            .initialize_projection()
+            .unwrap();
+
+        let proj_str = format!(
+            "+proj=gnom +lon_0={} +lat_0={} +ellps=sphere",
+            ref_lon, ref_lat
+        );
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+#[test]
+fn test_ellipsoidal_example() {
+    // Example from GeographicLib documentation of ellipsoidal gnomonic projection
+    let proj = Gnomonic::builder()
+        .ref_lonlat(2.0 + 20.0 / 60.0, 48.0 + 50.0 / 60.0)
+        .ellipsoid(Ellipsoid::WGS84)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(1.8, 50.9).unwrap();
+
+    assert_approx_eq!(f64, x, -37_543.7, epsilon = 0.1);
+    assert_approx_eq!(f64, y, 230_103.0, epsilon = 0.5);
+
+    let (lon, lat) = proj.inverse_project(-38e3, 230e3).unwrap();
+
+    assert_approx_eq!(f64, lon, 1.793_53, epsilon = 0.000_01);
+    assert_approx_eq!(f64, lat, 50.899, epsilon = 0.001);
+
+    let (x, y) = proj.project(lon, lat).unwrap();
+
+    assert_approx_eq!(f64, x, -38e3, epsilon = 0.000_001);
+    assert_approx_eq!(f64, y, 230e3, epsilon = 0.000_001);
+}
+
+#[test]
+fn test_not_visible() {
+    for ellps in [Ellipsoid::WGS84, Ellipsoid::SPHERE] {
+        let proj = Gnomonic::builder()
+            .ref_lonlat(29.0, 31.0)
+            .ellipsoid(ellps)
+            .initialize_projection()
+            .unwrap();
+
+        let err = proj.project(-151.0, -31.0).unwrap_err();
+        assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+
+        let err = proj.project(29.0, -60.0).unwrap_err();
+        assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+
+        assert!(proj.project(29.0, -58.0).is_ok());
+    }
+
+    let proj = Gnomonic::builder()
+        .ref_lonlat(29.0, 31.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let err = proj.project(29.0, -59.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+}
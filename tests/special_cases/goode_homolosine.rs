@@ -0,0 +1,200 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{
+        GoodeHomolosine, Hemisphere, Interrupted, Lobe, Mollweide, Sinusoidal,
+        goode_homolosine::INTERRUPTED_LOBES,
+    },
+};
+use std::f64::consts::PI;
+
+pub(crate) fn basic_correctness() {
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        // Proj goode uses rounded constants for the Mollweide part,
+        // so only points in the sinusoidal part can be compared with it
+        let int_proj = GoodeHomolosine::builder()
+            .central_lon(29.0)
+            .ellipsoid(ellps)
+            .initialize_projection()
+            .unwrap();
+        let proj_str = format!("+proj=goode +lon_0=29.0 +ellps={}", ellps_name);
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+pub(crate) fn interrupted_correctness() {
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        let int_proj = GoodeHomolosine::interrupted(ellps).unwrap();
+        let proj_str = format!("+proj=igh +ellps={}", ellps_name);
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Global);
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+#[test]
+fn test_continuity() {
+    let proj = GoodeHomolosine::builder()
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let phi_limit = 40.0 + 44.0 / 60.0 + 11.8 / 3600.0;
+
+    for lat in [phi_limit, -phi_limit] {
+        let (x_s, y_s) = proj.project(60.0, lat).unwrap();
+        let (x_m, y_m) = proj.project(60.0, lat + 1e-9_f64.copysign(lat)).unwrap();
+
+        // Lengths of parallels in both parts are equal only up to the precision of transition latitude
+        assert_approx_eq!(f64, x_s, x_m, epsilon = 2.0);
+        assert_approx_eq!(f64, y_s, y_m, epsilon = 0.001);
+    }
+}
+
+#[test]
+fn test_interruptions() {
+    let igh = GoodeHomolosine::interrupted(Ellipsoid::WGS84).unwrap();
+
+    // Points on lobe boundaries are projected with the western lobe
+    let (x_west, y_west) = igh.project(-40.0, 60.0).unwrap();
+    let (lon, lat) = igh.inverse_project(x_west, y_west).unwrap();
+    assert_approx_eq!(f64, lon, -40.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 60.0, epsilon = 0.000_000_1);
+
+    // Away from the Equator the lobes do not meet, so there is a gap between them
+    let (x_east, _) = igh.project(-39.999_999, 60.0).unwrap();
+    let x_gap = (x_west + x_east) / 2.0;
+    let err = igh.inverse_project(x_gap, y_west).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointInInterruption(..)));
+    assert!(igh.inverse_project_unchecked(x_gap, y_west).0.is_nan());
+
+    // Points beyond the poles are not in the interruption
+    let err = igh.inverse_project(0.0, 1.0e8).unwrap_err();
+    assert!(std::matches!(
+        err,
+        ProjectionError::InverseProjectionImpossible(..)
+    ));
+}
+
+#[test]
+fn test_interrupted_mollweide() {
+    let base = Mollweide::builder()
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    // Southern hemisphere is not covered by any lobe
+    let proj = Interrupted::builder()
+        .base_projection(base)
+        .lobes([
+            Lobe::new(-180.0, 0.0, -90.0, Hemisphere::North),
+            Lobe::new(0.0, 180.0, 90.0, Hemisphere::North),
+        ])
+        .initialize_projection()
+        .unwrap();
+
+    for lon in [-170.0, -90.0, -10.0, 10.0, 90.0, 170.0] {
+        let (x, y) = proj.project(lon, 45.0).unwrap();
+        let (tst_lon, tst_lat) = proj.inverse_project(x, y).unwrap();
+
+        assert_approx_eq!(f64, lon, tst_lon, epsilon = 0.000_000_1);
+        assert_approx_eq!(f64, 45.0, tst_lat, epsilon = 0.000_000_1);
+    }
+
+    // Central meridians of lobes are at the same place as in the base projection
+    let (x, _) = proj.project(90.0, 45.0).unwrap();
+    assert_approx_eq!(f64, x, base.project(90.0, 0.0).unwrap().0, epsilon = 0.001);
+
+    let err = proj.project(30.0, -45.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointInInterruption(..)));
+    assert!(proj.project_unchecked(30.0, -45.0).0.is_nan());
+}
+
+#[test]
+fn test_interrupted_sinusoidal() {
+    let r = Ellipsoid::SPHERE.A;
+
+    let base = Sinusoidal::builder()
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let proj = Interrupted::builder()
+        .base_projection(base)
+        .lobes(INTERRUPTED_LOBES)
+        .initialize_projection()
+        .unwrap();
+
+    // Points beyond the edges of lobes must not be accepted by any of them
+    for x in [1.5 * PI * r, 2.0 * PI * r, -1.5 * PI * r] {
+        assert!(proj.inverse_project(x, 1000.0).is_err());
+        assert!(proj.inverse_project_unchecked(x, 1000.0).0.is_nan());
+    }
+
+    let (x, y) = proj.project(170.0, 10.0).unwrap();
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+    assert_approx_eq!(f64, lon, 170.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 10.0, epsilon = 0.000_000_1);
+
+    // Longitudes are normalised before finding the lobe
+    let (tst_x, tst_y) = proj.project(-190.0, 10.0).unwrap();
+    assert_approx_eq!(f64, tst_x, x, epsilon = 0.001);
+    assert_approx_eq!(f64, tst_y, y, epsilon = 0.001);
+
+    let (x, y) = proj.project(190.0, 10.0).unwrap();
+    let (tst_x, tst_y) = proj.project(-170.0, 10.0).unwrap();
+    assert_approx_eq!(f64, tst_x, x, epsilon = 0.001);
+    assert_approx_eq!(f64, tst_y, y, epsilon = 0.001);
+}
+
+#[test]
+fn test_constructor() {
+    let base = GoodeHomolosine::builder().initialize_projection().unwrap();
+
+    let proj = Interrupted::<GoodeHomolosine, 0>::builder()
+        .base_projection(base)
+        .lobes([])
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = Interrupted::builder()
+        .base_projection(base)
+        .lobes([
+            Lobe::new(-180.0, 10.0, -90.0, Hemisphere::North),
+            Lobe::new(0.0, 180.0, 90.0, Hemisphere::North),
+        ])
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = Interrupted::builder()
+        .base_projection(base)
+        .lobes([Lobe::new(-190.0, 0.0, -90.0, Hemisphere::North)])
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = Interrupted::builder()
+        .base_projection(base)
+        .lobes([Lobe::new(-180.0, 180.0, 90.0, Hemisphere::South)])
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = Interrupted::<GoodeHomolosine, 1>::builder()
+        .lobes([Lobe::new(-180.0, 180.0, 0.0, Hemisphere::South)])
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamRequired(..)));
+
+    let proj = GoodeHomolosine::builder()
+        .central_lon(180.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+}
@@ -0,0 +1,149 @@
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{Healpix, RHealpix},
+};
+
+#[test]
+fn test_healpix_cell_id() {
+    // Reference identifiers computed with ang2pix_nest from the HEALPix library
+    let proj = Healpix::builder()
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    let points = [
+        ((0.5, 0.5), [4, 304, 4_980_818]),
+        ((45.0, 60.0), [0, 51, 839_631]),
+        ((-45.0, 60.0), [3, 243, 3_985_359]),
+        ((135.0, 60.0), [1, 115, 1_888_207]),
+        ((-135.0, 60.0), [2, 179, 2_936_783]),
+        ((90.0, 10.0), [5, 371, 6_078_515]),
+        ((-90.0, -10.0), [7, 460, 7_552_972]),
+        ((179.0, -5.0), [6, 399, 6_546_817]),
+        ((45.0, -60.0), [8, 524, 8_597_552]),
+        ((-120.0, -75.0), [10, 641, 10_516_966]),
+        ((12.34, 56.78), [0, 47, 770_995]),
+        ((-100.1, 30.2), [7, 510, 8_366_711]),
+        ((170.5, -44.4), [9, 596, 9_780_281]),
+        ((29.0, 89.5), [0, 63, 1_048_544]),
+    ];
+
+    for ((lon, lat), ids) in points {
+        let (x, y) = proj.project(lon, lat).unwrap();
+
+        for (resolution, id) in [0, 3, 10].into_iter().zip(ids) {
+            assert_eq!(proj.cell_id(x, y, resolution).unwrap(), id);
+        }
+    }
+}
+
+#[test]
+fn test_healpix_cell_center() {
+    let proj = Healpix::builder()
+        .central_lon(29.0)
+        .initialize_projection()
+        .unwrap();
+
+    let (lon, lat) = proj.cell_center(4, 0).unwrap();
+    assert_approx_eq!(f64, lon, 29.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 0.0, epsilon = 0.000_000_1);
+
+    // Centers of all cells are within the cells
+    for id in 0..12 * 4_u64.pow(3) {
+        let (lon, lat) = proj.cell_center(id, 3).unwrap();
+        let (x, y) = proj.project(lon, lat).unwrap();
+
+        assert_eq!(proj.cell_id(x, y, 3).unwrap(), id);
+    }
+}
+
+#[test]
+fn test_rhealpix_cell_id() {
+    let proj = RHealpix::builder()
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+
+    // Q3, N4 and S4 cells at resolution 1
+    let points = [((1.0, 1.0), 30), ((0.0, 90.0), 4), ((0.0, -90.0), 49)];
+
+    for ((lon, lat), id) in points {
+        let (x, y) = proj.project(lon, lat).unwrap();
+
+        assert_eq!(proj.cell_id(x, y, 0).unwrap(), id / 9);
+        assert_eq!(proj.cell_id(x, y, 1).unwrap(), id);
+    }
+}
+
+#[test]
+fn test_rhealpix_cell_center() {
+    let proj = RHealpix::builder()
+        .central_lon(29.0)
+        .north_square(1)
+        .south_square(3)
+        .initialize_projection()
+        .unwrap();
+
+    let (_, lat) = proj.cell_center(0, 0).unwrap();
+    assert_approx_eq!(f64, lat, 90.0, epsilon = 0.000_000_1);
+
+    // Centers of all cells are within the cells
+    for id in 0..6 * 9_u64.pow(2) {
+        let (lon, lat) = proj.cell_center(id, 2).unwrap();
+        let (x, y) = proj.project(lon, lat).unwrap();
+
+        assert_eq!(proj.cell_id(x, y, 2).unwrap(), id);
+    }
+}
+
+#[test]
+fn test_outside_map() {
+    let healpix = Healpix::builder().initialize_projection().unwrap();
+    let rhealpix = RHealpix::builder().initialize_projection().unwrap();
+
+    // Between polar caps of HEALPix and beside polar squares of rHEALPix
+    let (x, y) = (0.0, 12_000_000.0);
+
+    assert!(healpix.inverse_project(x, y).is_err());
+    assert!(rhealpix.inverse_project(x, y).is_err());
+
+    let err = healpix.cell_id(x, y, 1).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::OutOfDomain(..)));
+
+    let err = rhealpix.cell_id(x, y, 1).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::OutOfDomain(..)));
+}
+
+#[test]
+fn test_invalid_cells() {
+    let healpix = Healpix::builder().initialize_projection().unwrap();
+    let rhealpix = RHealpix::builder().initialize_projection().unwrap();
+
+    let err = healpix.cell_center(48, 1).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::InvalidCellId(48, 1)));
+
+    let err = rhealpix.cell_center(54, 1).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::InvalidCellId(54, 1)));
+
+    let err = healpix.cell_id(0.0, 0.0, 30).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange(..)));
+
+    let err = rhealpix.cell_center(0, 20).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange(..)));
+}
+
+#[test]
+fn test_constructor() {
+    let proj = RHealpix::builder()
+        .north_square(4)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+
+    let proj = Healpix::builder()
+        .central_lon(f64::NAN)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamNotFinite(..)));
+}
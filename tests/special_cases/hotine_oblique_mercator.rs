@@ -0,0 +1,73 @@
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{HotineObliqueMercator, hotine_oblique_mercator::HotineVariant},
+};
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from EPSG Guidance Note 7-2, section 3.2.4 (Timbalai 1948 / RSO Borneo),
+    // both variants give the same coordinates as false origins are chosen accordingly
+    let everest_1967 = Ellipsoid::new(6_377_298.556, 300.8017);
+
+    for (variant, false_easting, false_northing) in [
+        (HotineVariant::A, 0.0, 0.0),
+        (HotineVariant::B, 590_476.87, 442_857.65),
+    ] {
+        let proj = HotineObliqueMercator::builder()
+            .center(115.0, 4.0)
+            .azimuth(53.315_820_472)
+            .rectified_grid_angle(53.130_102_361)
+            .scale_factor(0.99984)
+            .false_origin(false_easting, false_northing)
+            .variant(variant)
+            .ellipsoid(everest_1967)
+            .initialize_projection()
+            .unwrap();
+
+        let (x, y) = proj.project(115.805_505_444, 5.387_253_583).unwrap();
+
+        assert_approx_eq!(f64, x, 679_245.73, epsilon = 0.01);
+        assert_approx_eq!(f64, y, 596_562.78, epsilon = 0.01);
+
+        let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+        assert_approx_eq!(f64, lon, 115.805_505_444, epsilon = 0.000_000_1);
+        assert_approx_eq!(f64, lat, 5.387_253_583, epsilon = 0.000_000_1);
+    }
+}
+
+#[test]
+fn test_constructor() {
+    let err = HotineObliqueMercator::builder()
+        .center(29.0, 31.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamRequired { .. }));
+
+    let err = HotineObliqueMercator::builder()
+        .center(29.0, -90.0)
+        .azimuth(30.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::IncorrectParams { .. }));
+
+    for (lat_1, lat_2) in [(0.0, 37.0), (25.0, 25.0), (25.0, -90.0)] {
+        let err = HotineObliqueMercator::builder()
+            .center(29.0, 31.0)
+            .two_points(20.0, lat_1, 40.0, lat_2)
+            .initialize_projection()
+            .unwrap_err();
+        assert!(std::matches!(err, ProjectionError::IncorrectParams { .. }));
+    }
+
+    // Setting the azimuth replaces the two-point definition
+    assert!(
+        HotineObliqueMercator::builder()
+            .center(29.0, 31.0)
+            .two_points(20.0, 0.0, 40.0, 37.0)
+            .azimuth(30.0)
+            .initialize_projection()
+            .is_ok()
+    );
+}
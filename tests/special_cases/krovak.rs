@@ -0,0 +1,111 @@
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::Krovak};
+
+pub(crate) fn basic_correctness() {
+    // Proj always uses parameters of the cone from S-JTSK and the Bessel ellipsoid,
+    // with squared eccentricity rounded to 0.006674372230614, but the ellipsoid still
+    // has to be given explicitly for the inverse projection to be consistent
+    let ellps = Ellipsoid::new(6_377_397.155, 299.152_812_853_345_4);
+
+    let int_proj = Krovak::builder()
+        .center(29.0, 30.0)
+        .scale_factor(0.9996)
+        .ellipsoid(ellps)
+        .initialize_projection()
+        .unwrap();
+    let proj_str = "+proj=krovak +lon_0=29.0 +lat_0=30.0 +k=0.9996 +ellps=bessel +czech";
+
+    test_points_with_proj(&int_proj, proj_str, TestExtent::Local);
+
+    let int_proj = Krovak::builder()
+        .center(29.0, 30.0)
+        .north_orientated(true)
+        .ellipsoid(ellps)
+        .initialize_projection()
+        .unwrap();
+    let proj_str = "+proj=krovak +lon_0=29.0 +lat_0=30.0 +ellps=bessel";
+
+    test_points_with_proj(&int_proj, proj_str, TestExtent::Local);
+}
+
+#[test]
+fn test_epsg_example() {
+    // Example from EPSG Guidance Note 7-2 for S-JTSK, given to the centimetre
+    let (lon, lat) = (
+        16.0 + 50.0 / 60.0 + 59.179 / 3600.0,
+        50.0 + 12.0 / 60.0 + 32.442 / 3600.0,
+    );
+    let (westing, southing) = (568_991.00, 1_050_538.64);
+
+    let proj = Krovak::builder().initialize_projection().unwrap();
+
+    let (x, y) = proj.project(lon, lat).unwrap();
+    assert_approx_eq!(f64, x, westing, epsilon = 0.011);
+    assert_approx_eq!(f64, y, southing, epsilon = 0.011);
+
+    let (tst_lon, tst_lat) = proj.inverse_project(westing, southing).unwrap();
+    assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_5);
+    assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_5);
+
+    let proj = Krovak::builder()
+        .north_orientated(true)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(lon, lat).unwrap();
+    assert_approx_eq!(f64, x, -westing, epsilon = 0.011);
+    assert_approx_eq!(f64, y, -southing, epsilon = 0.011);
+
+    let (tst_lon, tst_lat) = proj.inverse_project(-westing, -southing).unwrap();
+    assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_5);
+    assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_5);
+}
+
+#[test]
+fn test_false_origin() {
+    let (lon, lat) = (14.42, 50.09);
+
+    let (ref_x, ref_y) = Krovak::builder()
+        .initialize_projection()
+        .unwrap()
+        .project(lon, lat)
+        .unwrap();
+
+    let proj = Krovak::builder()
+        .false_origin(5_000_000.0, 5_000_000.0)
+        .north_orientated(true)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(lon, lat).unwrap();
+    assert_approx_eq!(f64, x, -ref_x - 5_000_000.0, epsilon = 0.000_001);
+    assert_approx_eq!(f64, y, -ref_y - 5_000_000.0, epsilon = 0.000_001);
+
+    let (tst_lon, tst_lat) = proj.inverse_project(x, y).unwrap();
+    assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let proj = Krovak::builder()
+        .pseudo_standard_parallel(0.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = Krovak::builder()
+        .cone_axis_colatitude(90.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+
+    let proj = Krovak::builder()
+        .scale_factor(0.0)
+        .ellipsoid(Ellipsoid::WGS84)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+}
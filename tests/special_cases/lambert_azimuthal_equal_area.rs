@@ -0,0 +1,44 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::LambertAzimuthalEqualArea};
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from EPSG Guidance Note 7-2 for ETRS89 / LAEA Europe (EPSG:3035),
+    // false easting and northing are subtracted from reference values
+    let proj = LambertAzimuthalEqualArea::builder()
+        .ref_lonlat(10.0, 52.0)
+        .ellipsoid(Ellipsoid::GRS80)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(5.0, 50.0).unwrap();
+
+    assert_approx_eq!(f64, x, 3_962_799.45 - 4_321_000.0, epsilon = 0.01);
+    assert_approx_eq!(f64, y, 2_999_718.85 - 3_210_000.0, epsilon = 0.01);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, 5.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 50.0, epsilon = 0.000_000_1);
+
+    let (lon, lat) = proj.inverse_project(0.0, 0.0).unwrap();
+
+    assert_approx_eq!(f64, lon, 10.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 52.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let laea = LambertAzimuthalEqualArea::builder()
+        .ref_lonlat(0.0, 90.1)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(laea, ProjectionError::ParamOutOfRange { .. }));
+
+    for ref_lat in [-90.0, 0.0, 90.0] {
+        let laea = LambertAzimuthalEqualArea::builder()
+            .ref_lonlat(0.0, ref_lat)
+            .initialize_projection();
+        assert!(laea.is_ok());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use mappers::{
+    Projection, ProjectionError,
+    projections::{Mercator, WebMercator},
+};
+
+pub(crate) fn basic_correctness() {
+    // This projection is defined only on WGS84 and uses spherical formulas
+    // so it is equivalent to Mercator on a sphere with WGS84 semi-major axis
+    let proj_str = "+proj=merc +a=6378137.0 +b=6378137.0";
+
+    test_points_with_proj(&WebMercator, proj_str, TestExtent::Global);
+    test_points_with_proj(&WebMercator, proj_str, TestExtent::Local);
+}
+
+#[test]
+fn test_domain() {
+    let merc = Mercator::builder()
+        .central_lon(29.0)
+        .initialize_projection()
+        .unwrap();
+
+    for lat in [85.06, -85.06, 89.0, -90.0] {
+        let merc_err = merc.project(30.0, lat).unwrap_err();
+        assert!(std::matches!(merc_err, ProjectionError::OutOfDomain { .. }));
+
+        let web_err = WebMercator.project(30.0, lat).unwrap_err();
+        assert!(std::matches!(web_err, ProjectionError::OutOfDomain { .. }));
+    }
+
+    for lat in [85.05, -85.05, 0.0] {
+        assert!(merc.project(30.0, lat).is_ok());
+        assert!(WebMercator.project(30.0, lat).is_ok());
+    }
+}
+
+#[test]
+fn test_constructor() {
+    let merc = Mercator::builder()
+        .scale_factor(-1.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(merc, ProjectionError::IncorrectParams { .. }));
+
+    let merc = Mercator::builder()
+        .standard_parallel(91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(merc, ProjectionError::ParamOutOfRange { .. }));
+}
@@ -1,4 +1,30 @@
+pub(crate) mod albers_equal_area_conic;
+pub(crate) mod azimuthal_equidistant;
+pub(crate) mod bonne;
+pub(crate) mod cassini_soldner;
+pub(crate) mod cubed_sphere;
+pub(crate) mod cylindrical_equal_area;
+pub(crate) mod equal_earth;
+pub(crate) mod equidistant_conic;
 pub(crate) mod equidistant_cylindrical;
+pub(crate) mod geostationary;
+pub(crate) mod gnomonic;
+pub(crate) mod goode_homolosine;
+pub(crate) mod healpix;
+pub(crate) mod hotine_oblique_mercator;
+pub(crate) mod krovak;
+pub(crate) mod lambert_azimuthal_equal_area;
 pub(crate) mod lambert_conformal_conic;
+pub(crate) mod mercator;
 pub(crate) mod modified_azimuthal_equidistant;
+pub(crate) mod near_sided_perspective;
 pub(crate) mod oblique_lon_lat;
+pub(crate) mod orthographic;
+pub(crate) mod polar_stereographic;
+pub(crate) mod polyconic;
+pub(crate) mod sinusoidal;
+pub(crate) mod stereographic;
+pub(crate) mod transverse_mercator;
+pub(crate) mod two_point_equidistant;
+pub(crate) mod van_der_grinten;
+pub(crate) mod winkel_tripel;
@@ -1,5 +1,11 @@
 use float_cmp::assert_approx_eq;
-use mappers::{Ellipsoid, Projection, projections::ModifiedAzimuthalEquidistant};
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{
+        ModifiedAzimuthalEquidistant,
+        modified_azimuthal_equidistant::ModifiedAzimuthalEquidistantVariant,
+    },
+};
 
 pub(crate) fn basic_correctness() {
     // This projection has to be tested with numerical example provided in Snyder
@@ -28,3 +34,96 @@ pub(crate) fn basic_correctness() {
     assert_approx_eq!(f64, lon, ref_lon, epsilon = 0.000_000_1);
     assert_approx_eq!(f64, lat, ref_lat, epsilon = 0.000_000_1);
 }
+
+#[test]
+fn test_guam() {
+    // Numerical example for Guam 1963 from EPSG Guidance Note 7-2
+    let proj = ModifiedAzimuthalEquidistant::builder()
+        .ref_lonlat(144.748_750_705_6, 13.472_466_352_8)
+        .variant(ModifiedAzimuthalEquidistantVariant::Guam)
+        .ellipsoid(Ellipsoid::CLARKE1866)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(144.635_331_291_7, 13.339_038_461_1).unwrap();
+
+    let ref_x = 37_712.48 - 50_000.0;
+    let ref_y = 35_242.00 - 50_000.0;
+
+    assert_approx_eq!(f64, x, ref_x, epsilon = 0.01);
+    assert_approx_eq!(f64, y, ref_y, epsilon = 0.01);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, 144.635_331_291_7, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 13.339_038_461_1, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_guam_antimeridian() {
+    let proj = ModifiedAzimuthalEquidistant::builder()
+        .ref_lonlat(144.75, 13.47)
+        .variant(ModifiedAzimuthalEquidistantVariant::Guam)
+        .initialize_projection()
+        .unwrap();
+
+    // Reference values from Proj
+    let (x, y) = proj.project(-179.0, 20.0).unwrap();
+    assert_approx_eq!(f64, x, 3_793_456.878_732, epsilon = 0.001);
+    assert_approx_eq!(f64, y, 1_133_091.119_619, epsilon = 0.001);
+
+    // Inverse with three iterations is only approximate so far from the centre
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+    assert_approx_eq!(f64, lon, -178.987_744_500_7, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 20.051_626_895_0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_accuracy_limit() {
+    for variant in [
+        ModifiedAzimuthalEquidistantVariant::Micronesia,
+        ModifiedAzimuthalEquidistantVariant::Guam,
+    ] {
+        let proj = ModifiedAzimuthalEquidistant::builder()
+            .ref_lonlat(145.741_658_9, 15.184_911_94)
+            .variant(variant)
+            .accuracy_limit(800_000.0)
+            .initialize_projection()
+            .unwrap();
+
+        assert!(proj.project(148.0, 18.0).is_ok());
+        assert!(proj.inverse_project(500_000.0, -500_000.0).is_ok());
+
+        let err = proj.project(160.0, 15.0).unwrap_err();
+        assert!(std::matches!(err, ProjectionError::OutOfDomain(..)));
+
+        let err = proj.inverse_project(600_000.0, -600_000.0).unwrap_err();
+        assert!(std::matches!(err, ProjectionError::OutOfDomain(..)));
+
+        // Unchecked functions are not limited
+        let (x, y) = proj.project_unchecked(160.0, 15.0);
+        assert!(x.is_finite() && y.is_finite());
+    }
+}
+
+#[test]
+fn test_constructor() {
+    let proj = ModifiedAzimuthalEquidistant::builder()
+        .ref_lonlat(145.0, 15.0)
+        .accuracy_limit(0.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = ModifiedAzimuthalEquidistant::builder()
+        .ref_lonlat(145.0, 15.0)
+        .accuracy_limit(f64::INFINITY)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamNotFinite(..)));
+
+    let proj = ModifiedAzimuthalEquidistant::builder()
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamRequired(..)));
+}
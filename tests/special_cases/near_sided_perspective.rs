@@ -0,0 +1,65 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use mappers::{Projection, ProjectionError, projections::NearSidedPerspective};
+
+pub(crate) fn basic_correctness() {
+    // Global test points are beyond the horizon, so only local points are tested
+
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        for height in [500_000.0, 3_000_000.0, 35_786_000.0] {
+            let int_proj = NearSidedPerspective::builder()
+                .ref_lonlat(29.0, 31.0)
+                .height(height)
+                .ellipsoid(ellps)
+                .initialize_projection()
+                .unwrap();
+
+            let proj_str = format!(
+                "+proj=nsper +lon_0=29.0 +lat_0=31.0 +h={} +ellps={}",
+                height, ellps_name
+            );
+
+            test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+        }
+    }
+}
+
+#[test]
+fn test_not_visible() {
+    let proj = NearSidedPerspective::builder()
+        .ref_lonlat(29.0, 31.0)
+        .height(500_000.0)
+        .initialize_projection()
+        .unwrap();
+
+    // Horizon is about 22 degrees away from the center at this height
+    assert!(proj.project(29.0, 52.0).is_ok());
+
+    let err = proj.project(29.0, 55.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+
+    let err = proj.inverse_project(3_000_000.0, 0.0).unwrap_err();
+    assert!(std::matches!(
+        err,
+        ProjectionError::InverseProjectionImpossible { .. }
+    ));
+}
+
+#[test]
+fn test_constructor() {
+    for height in [0.0, -100.0] {
+        let err = NearSidedPerspective::builder()
+            .ref_lonlat(29.0, 31.0)
+            .height(height)
+            .initialize_projection()
+            .unwrap_err();
+        assert!(std::matches!(err, ProjectionError::IncorrectParams { .. }));
+    }
+
+    let err = NearSidedPerspective::builder()
+        .ref_lonlat(29.0, 31.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamRequired { .. }));
+}
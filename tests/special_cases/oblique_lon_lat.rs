@@ -2,8 +2,9 @@ use crate::GLOBAL_GEO_POINTS;
 use crate::LOCAL_GEO_POINTS;
 use crate::TestExtent;
 use float_cmp::assert_approx_eq;
-use mappers::Projection;
 use mappers::projections::ObliqueLonLat;
+use mappers::projections::oblique_lon_lat::LatitudeMode;
+use mappers::{Ellipsoid, Projection};
 use proj::Proj;
 
 pub(crate) fn basic_correctness() {
@@ -31,12 +32,69 @@ pub(crate) fn basic_correctness() {
     }
 }
 
+pub(crate) fn geocentric_correctness() {
+    // Proj has no such option in ob_tran, so geocentric latitude
+    // is computed in a separate step of the pipeline
+
+    for (pole_lon, pole_lat, central_lon) in [(-170.0, 40.0, 10.0), (30.0, -25.0, -60.0)] {
+        let int_proj = ObliqueLonLat::builder()
+            .pole_lonlat(pole_lon, pole_lat)
+            .central_lon(central_lon)
+            .latitude_mode(LatitudeMode::Geocentric)
+            .ellipsoid(Ellipsoid::WGS84)
+            .initialize_projection()
+            .unwrap();
+
+        let proj_str = format!(
+            "+proj=pipeline +step +proj=geoc +ellps=WGS84 +step +proj=ob_tran +o_proj=latlon +o_lat_p={} +o_lon_p={} +lon_0={} +ellps=sphere",
+            pole_lat, pole_lon, central_lon
+        );
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Global);
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+#[test]
+fn test_conformal() {
+    // Conformal latitude is recovered from the ellipsoidal Mercator northing,
+    // and then rotated on the sphere without any conversion
+    let merc = Proj::new("+proj=merc +ellps=WGS84").unwrap();
+
+    let mut builder = ObliqueLonLat::builder();
+    builder.pole_lonlat(-170.0, 40.0).central_lon(10.0);
+    let spherical = builder.initialize_projection().unwrap();
+    let conformal = builder
+        .latitude_mode(LatitudeMode::Conformal)
+        .initialize_projection()
+        .unwrap();
+
+    for (lon, lat) in GLOBAL_GEO_POINTS.into_iter().chain(LOCAL_GEO_POINTS) {
+        let (_, y) = merc
+            .project((lon.to_radians(), lat.to_radians()), false)
+            .unwrap();
+        let chi = (y / Ellipsoid::WGS84.A).sinh().atan().to_degrees();
+
+        let (ref_lon, ref_lat) = spherical.project(lon, chi).unwrap();
+        let (tst_lon, tst_lat) = conformal.project(lon, lat).unwrap();
+
+        assert_approx_eq!(f64, ref_lon, tst_lon, epsilon = 0.000_000_1);
+        assert_approx_eq!(f64, ref_lat, tst_lat, epsilon = 0.000_000_1);
+
+        let (inv_lon, inv_lat) = conformal.inverse_project(tst_lon, tst_lat).unwrap();
+
+        assert_approx_eq!(f64, lon, inv_lon, epsilon = 0.000_000_1);
+        assert_approx_eq!(f64, lat, inv_lat, epsilon = 0.000_000_1);
+    }
+}
+
 fn test_points_with_proj(int_proj: &ObliqueLonLat, proj_str: &str, extent: TestExtent) {
     let ref_proj = Proj::new(proj_str).unwrap();
 
     let geo_points = match extent {
         TestExtent::Global => GLOBAL_GEO_POINTS,
         TestExtent::Local => LOCAL_GEO_POINTS,
+        TestExtent::Antimeridian => unimplemented!(),
     };
 
     for point in geo_points {
@@ -0,0 +1,83 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::Orthographic};
+
+pub(crate) fn basic_correctness() {
+    // Most of global test points are on the far side of the globe,
+    // so only local points are tested
+
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        let int_proj = Orthographic::builder()
+            .ref_lonlat(29.0, 31.0)
+            .ellipsoid(ellps)
+            .initialize_projection()
+            .unwrap();
+
+        let proj_str = format!("+proj=ortho +lon_0=29.0 +lat_0=31.0 +ellps={}", ellps_name);
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from Snyder (1987) p. 311-312
+    let unit_sphere = Ellipsoid {
+        A: 1.0,
+        B: 1.0,
+        E: 0.0,
+        F: 0.0,
+    };
+
+    let proj = Orthographic::builder()
+        .ref_lonlat(-100.0, 40.0)
+        .ellipsoid(unit_sphere)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-110.0, 30.0).unwrap();
+
+    assert_approx_eq!(f64, x, -0.150_383_7, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, y, -0.165_191_1, epsilon = 0.000_000_1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -110.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 30.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_not_visible() {
+    let proj = Orthographic::builder()
+        .ref_lonlat(29.0, 31.0)
+        .initialize_projection()
+        .unwrap();
+
+    let err = proj.project(-151.0, -31.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+
+    let err = proj.project(-135.0, 45.0).unwrap_err();
+    assert!(std::matches!(err, ProjectionError::PointNotVisible { .. }));
+
+    let err = proj.inverse_project(7_000_000.0, 0.0).unwrap_err();
+    assert!(std::matches!(
+        err,
+        ProjectionError::InverseProjectionImpossible { .. }
+    ));
+}
+
+#[test]
+fn test_constructor() {
+    let err = Orthographic::builder()
+        .ref_lonlat(29.0, 91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange { .. }));
+
+    let proj = Orthographic::builder()
+        .ref_lonlat(29.0, -90.0)
+        .initialize_projection();
+    assert!(proj.is_ok());
+}
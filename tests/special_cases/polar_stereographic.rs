@@ -0,0 +1,96 @@
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{Hemisphere, PolarStereographic},
+};
+
+#[test]
+fn test_opposite_pole() {
+    let north = PolarStereographic::builder()
+        .true_scale_lat(70.0)
+        .initialize_projection()
+        .unwrap();
+
+    let stere_err = north.project(0.0, -90.0).unwrap_err();
+    assert!(std::matches!(
+        stere_err,
+        ProjectionError::ProjectionImpossible { .. }
+    ));
+    assert!(north.project(0.0, 90.0).is_ok());
+
+    let south = PolarStereographic::builder()
+        .pole(Hemisphere::South)
+        .true_scale_lat(-70.0)
+        .initialize_projection()
+        .unwrap();
+
+    let stere_err = south.project(0.0, 90.0).unwrap_err();
+    assert!(std::matches!(
+        stere_err,
+        ProjectionError::ProjectionImpossible { .. }
+    ));
+    assert!(south.project(0.0, -90.0).is_ok());
+}
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from Snyder (1987) p. 315-316
+    let proj = PolarStereographic::builder()
+        .pole(Hemisphere::South)
+        .straight_vertical_lon(-100.0)
+        .true_scale_lat(-71.0)
+        .ellipsoid(Ellipsoid::new(6_378_388.0, 297.0))
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(150.0, -75.0).unwrap();
+
+    assert_approx_eq!(f64, x, -1_540_033.6, epsilon = 0.1);
+    assert_approx_eq!(f64, y, -560_526.4, epsilon = 0.1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, 150.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, -75.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let stere = PolarStereographic::builder()
+        .true_scale_lat(-60.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(
+        stere,
+        ProjectionError::IncorrectParams { .. }
+    ));
+
+    let stere = PolarStereographic::builder()
+        .true_scale_lat(91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(
+        stere,
+        ProjectionError::ParamOutOfRange { .. }
+    ));
+
+    let stere = PolarStereographic::builder()
+        .scale_factor(0.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(
+        stere,
+        ProjectionError::IncorrectParams { .. }
+    ));
+
+    // Scale true at the pole is the same as scale factor equal 1
+    let ts_stere = PolarStereographic::builder()
+        .true_scale_lat(90.0)
+        .initialize_projection()
+        .unwrap();
+    let k0_stere = PolarStereographic::builder()
+        .scale_factor(1.0)
+        .initialize_projection()
+        .unwrap();
+    assert_eq!(ts_stere, k0_stere);
+}
@@ -0,0 +1,48 @@
+use float_cmp::assert_approx_eq;
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::Polyconic};
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from Snyder (1987), p. 295
+    let proj = Polyconic::builder()
+        .ref_lonlat(-96.0, 30.0)
+        .ellipsoid(Ellipsoid::CLARKE1866)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-75.0, 40.0).unwrap();
+
+    assert_approx_eq!(f64, x, 1_776_774.5, epsilon = 0.1);
+    assert_approx_eq!(f64, y, 1_319_657.8, epsilon = 0.1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -75.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 40.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_equator() {
+    let proj = Polyconic::builder()
+        .ref_lonlat(-96.0, 30.0)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-90.0, 0.0).unwrap();
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, -90.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 0.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let err = Polyconic::builder().initialize_projection().unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamRequired { .. }));
+
+    let err = Polyconic::builder()
+        .ref_lonlat(180.0, 31.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange { .. }));
+}
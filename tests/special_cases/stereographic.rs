@@ -0,0 +1,114 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Ellipsoid, Projection, ProjectionError,
+    projections::{Stereographic, stereographic::StereographicVariant},
+};
+use proj::Proj;
+
+pub(crate) fn basic_correctness() {
+    // Global test points lie close to the point opposite to the center of projection,
+    // where projected values are too big to be compared with absolute tolerance,
+    // so only local points are tested
+
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        for (variant, proj_name) in [
+            (StereographicVariant::Oblique, "sterea"),
+            (StereographicVariant::Snyder, "stere"),
+        ] {
+            let int_proj = Stereographic::builder()
+                .ref_lonlat(29.0, 31.0)
+                .scale_factor(0.9999)
+                .variant(variant)
+                .ellipsoid(ellps)
+                .initialize_projection()
+                .unwrap();
+
+            let proj_str = format!(
+                "+proj={} +lon_0=29.0 +lat_0=31.0 +k=0.9999 +ellps={}",
+                proj_name, ellps_name
+            );
+
+            test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+        }
+    }
+}
+
+#[test]
+fn test_antimeridian() {
+    for (variant, proj_name) in [
+        (StereographicVariant::Oblique, "sterea"),
+        (StereographicVariant::Snyder, "stere"),
+    ] {
+        let int_proj = Stereographic::builder()
+            .ref_lonlat(178.0, 40.0)
+            .variant(variant)
+            .initialize_projection()
+            .unwrap();
+
+        let ref_proj = Proj::new(&format!(
+            "+proj={proj_name} +lon_0=178.0 +lat_0=40.0 +ellps=WGS84"
+        ))
+        .unwrap();
+
+        for (lon, lat) in [(-178.0, 45.0), (-175.0, 35.0), (179.0, 38.0)] {
+            let (ref_x, ref_y) = ref_proj
+                .project((f64::to_radians(lon), f64::to_radians(lat)), false)
+                .unwrap();
+
+            let (x, y) = int_proj.project(lon, lat).unwrap();
+            assert_approx_eq!(f64, x, ref_x, epsilon = 0.000_000_1);
+            assert_approx_eq!(f64, y, ref_y, epsilon = 0.000_000_1);
+
+            let (tst_lon, tst_lat) = int_proj.inverse_project(x, y).unwrap();
+            assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_1);
+            assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_1);
+        }
+    }
+}
+
+#[test]
+fn test_numerical_example() {
+    // Numerical example from EPSG Guidance Note 7-2 for Amersfoort / RD New,
+    // false easting and northing are subtracted from reference values
+    let proj = Stereographic::builder()
+        .ref_lonlat(5.387_638_889, 52.156_160_556)
+        .scale_factor(0.999_907_9)
+        .ellipsoid(Ellipsoid::new(6_377_397.155, 299.152_812_8))
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(6.0, 53.0).unwrap();
+
+    assert_approx_eq!(f64, x, 196_105.283 - 155_000.0, epsilon = 0.001);
+    assert_approx_eq!(f64, y, 557_057.739 - 463_000.0, epsilon = 0.001);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, 6.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 53.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    let stere = Stereographic::builder()
+        .ref_lonlat(0.0, -90.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(
+        stere,
+        ProjectionError::IncorrectParams { .. }
+    ));
+
+    let stere = Stereographic::builder()
+        .ref_lonlat(0.0, 45.0)
+        .scale_factor(0.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(
+        stere,
+        ProjectionError::IncorrectParams { .. }
+    ));
+}
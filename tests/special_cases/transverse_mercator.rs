@@ -0,0 +1,118 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{
+    Projection, ProjectionError,
+    projections::{Hemisphere, TransverseMercator, transverse_mercator::utm_zone},
+};
+
+pub(crate) fn basic_correctness() {
+    // Proj does not project points further than 90° from the central meridian
+    // on the sphere, so only local points are tested
+
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        let int_proj = TransverseMercator::builder()
+            .ref_lonlat(29.0, 31.0)
+            .scale_factor(0.9996)
+            .ellipsoid(ellps)
+            .initialize_projection()
+            .unwrap();
+
+        let proj_str = format!(
+            "+proj=tmerc +lon_0=29.0 +lat_0=31.0 +k=0.9996 +ellps={}",
+            ellps_name
+        );
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Local);
+    }
+}
+
+pub(crate) fn utm_correctness() {
+    // UTM adds false easting and northing so it is tested separately
+    // with zones placed around the test points
+
+    for (zone, hemisphere, proj_str) in [
+        (36, Hemisphere::North, "+proj=utm +zone=36 +ellps=WGS84"),
+        (35, Hemisphere::North, "+proj=utm +zone=35 +ellps=WGS84"),
+        (
+            36,
+            Hemisphere::South,
+            "+proj=utm +zone=36 +south +ellps=WGS84",
+        ),
+    ] {
+        let int_proj = TransverseMercator::utm(zone, hemisphere).unwrap();
+
+        test_points_with_proj(&int_proj, proj_str, TestExtent::Local);
+    }
+}
+
+#[test]
+fn test_utm_zone() {
+    assert_eq!(utm_zone(-180.0, 0.0).unwrap(), 1);
+    assert_eq!(utm_zone(-177.0, -45.0).unwrap(), 1);
+    assert_eq!(utm_zone(0.0, 0.0).unwrap(), 31);
+    assert_eq!(utm_zone(21.0, 52.0).unwrap(), 34);
+    assert_eq!(utm_zone(179.9, 0.0).unwrap(), 60);
+
+    // Norway
+    assert_eq!(utm_zone(5.0, 60.0).unwrap(), 32);
+    assert_eq!(utm_zone(5.0, 55.0).unwrap(), 31);
+    assert_eq!(utm_zone(2.0, 60.0).unwrap(), 31);
+
+    // Svalbard
+    assert_eq!(utm_zone(5.0, 78.0).unwrap(), 31);
+    assert_eq!(utm_zone(10.0, 78.0).unwrap(), 33);
+    assert_eq!(utm_zone(25.0, 78.0).unwrap(), 35);
+    assert_eq!(utm_zone(35.0, 78.0).unwrap(), 37);
+    assert_eq!(utm_zone(45.0, 78.0).unwrap(), 38);
+
+    assert!(std::matches!(
+        utm_zone(0.0, 85.0).unwrap_err(),
+        ProjectionError::ParamOutOfRange { .. }
+    ));
+    assert!(std::matches!(
+        utm_zone(0.0, -81.0).unwrap_err(),
+        ProjectionError::ParamOutOfRange { .. }
+    ));
+}
+
+#[test]
+fn test_antimeridian() {
+    let proj = TransverseMercator::builder()
+        .ref_lonlat(179.0, 0.0)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(-179.0, 10.0).unwrap();
+    assert!(x > 0.0);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+    assert_approx_eq!(f64, lon, -179.0, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 10.0, epsilon = 0.000_000_1);
+}
+
+#[test]
+fn test_constructor() {
+    for zone in 1..=60 {
+        assert!(TransverseMercator::utm(zone, Hemisphere::North).is_ok());
+    }
+
+    for zone in [0, 61] {
+        let tmerc = TransverseMercator::utm(zone, Hemisphere::North).unwrap_err();
+        assert!(std::matches!(
+            tmerc,
+            ProjectionError::ParamOutOfRange { .. }
+        ));
+    }
+
+    let tmerc = TransverseMercator::builder()
+        .ref_lonlat(15.0, 0.0)
+        .scale_factor(0.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(
+        tmerc,
+        ProjectionError::IncorrectParams { .. }
+    ));
+}
@@ -0,0 +1,79 @@
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+use mappers::{Ellipsoid, Projection, ProjectionError, projections::TwoPointEquidistant};
+
+pub(crate) fn basic_correctness() {
+    // Proj defines this projection only for the sphere
+    let int_proj = TwoPointEquidistant::builder()
+        .control_points(20.0, 20.0, 40.0, 25.0)
+        .ellipsoid(Ellipsoid::SPHERE)
+        .initialize_projection()
+        .unwrap();
+    let proj_str = "+proj=tpeqd +lon_1=20.0 +lat_1=20.0 +lon_2=40.0 +lat_2=25.0 +ellps=sphere";
+
+    test_points_with_proj(&int_proj, proj_str, TestExtent::Global);
+    test_points_with_proj(&int_proj, proj_str, TestExtent::Local);
+}
+
+#[test]
+fn test_ellipsoidal_distances() {
+    // Frankfurt and Singapore airports
+    let hubs = [(8.57, 50.03), (103.99, 1.36)];
+
+    let proj = TwoPointEquidistant::builder()
+        .control_points(hubs[0].0, hubs[0].1, hubs[1].0, hubs[1].1)
+        .ellipsoid(Ellipsoid::WGS84)
+        .initialize_projection()
+        .unwrap();
+
+    let geod = Geodesic::wgs84();
+    let d: f64 = geod.inverse(hubs[0].1, hubs[0].0, hubs[1].1, hubs[1].0);
+
+    let (x, y) = proj.project(hubs[0].0, hubs[0].1).unwrap();
+    assert_approx_eq!(f64, x, -d / 2.0, epsilon = 0.001);
+    assert_approx_eq!(f64, y, 0.0, epsilon = 0.001);
+
+    let (x, y) = proj.project(hubs[1].0, hubs[1].1).unwrap();
+    assert_approx_eq!(f64, x, d / 2.0, epsilon = 0.001);
+    assert_approx_eq!(f64, y, 0.0, epsilon = 0.001);
+
+    // Points to the left of the route are above it on the map
+    assert!(proj.project(37.41, 55.97).unwrap().1 > 0.0);
+    assert!(proj.project(28.0, 10.0).unwrap().1 < 0.0);
+
+    for (lon, lat) in [(37.41, 55.97), (55.36, 25.25), (72.87, 19.09), (28.0, 10.0)] {
+        let (x, y) = proj.project(lon, lat).unwrap();
+
+        let d_1: f64 = geod.inverse(hubs[0].1, hubs[0].0, lat, lon);
+        let d_2: f64 = geod.inverse(hubs[1].1, hubs[1].0, lat, lon);
+
+        assert_approx_eq!(f64, (x + d / 2.0).hypot(y), d_1, epsilon = 0.001);
+        assert_approx_eq!(f64, (x - d / 2.0).hypot(y), d_2, epsilon = 0.001);
+
+        let (tst_lon, tst_lat) = proj.inverse_project(x, y).unwrap();
+        assert_approx_eq!(f64, tst_lon, lon, epsilon = 0.000_000_1);
+        assert_approx_eq!(f64, tst_lat, lat, epsilon = 0.000_000_1);
+    }
+}
+
+#[test]
+fn test_constructor() {
+    let proj = TwoPointEquidistant::builder()
+        .control_points(10.0, 20.0, 10.0, 20.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::IncorrectParams(..)));
+
+    let proj = TwoPointEquidistant::builder()
+        .control_points(10.0, 20.0, 10.0, 91.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamOutOfRange(..)));
+
+    let proj = TwoPointEquidistant::builder()
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(proj, ProjectionError::ParamRequired(..)));
+}
@@ -0,0 +1,41 @@
+use crate::ELLIPSOIDS_TEST_SET;
+use crate::TestExtent;
+use crate::test_points_with_proj;
+use float_cmp::assert_approx_eq;
+use mappers::{Projection, projections::VanDerGrinten};
+
+pub(crate) fn basic_correctness() {
+    // Proj loses precision close to the central meridian due to cancellation,
+    // so only global points are compared and local points are tested against
+    // reference values computed with extended precision
+
+    for (ellps, ellps_name) in ELLIPSOIDS_TEST_SET {
+        let int_proj = VanDerGrinten::builder()
+            .central_lon(29.0)
+            .ellipsoid(ellps)
+            .initialize_projection()
+            .unwrap();
+
+        let proj_str = format!("+proj=vandg +lon_0=29.0 +ellps={}", ellps_name);
+
+        test_points_with_proj(&int_proj, &proj_str, TestExtent::Global);
+    }
+}
+
+#[test]
+fn test_reference_values() {
+    let proj = VanDerGrinten::builder()
+        .central_lon(29.0)
+        .initialize_projection()
+        .unwrap();
+
+    let (x, y) = proj.project(31.48, 31.26).unwrap();
+
+    assert_approx_eq!(f64, x, 267_203.389_416_584, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, y, 3_591_767.454_803_084, epsilon = 0.000_000_1);
+
+    let (lon, lat) = proj.inverse_project(x, y).unwrap();
+
+    assert_approx_eq!(f64, lon, 31.48, epsilon = 0.000_000_1);
+    assert_approx_eq!(f64, lat, 31.26, epsilon = 0.000_000_1);
+}
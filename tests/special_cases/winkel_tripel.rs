@@ -0,0 +1,44 @@
+use mappers::{
+    Projection, ProjectionError,
+    projections::{Aitoff, Hammer, VanDerGrinten, WinkelTripel},
+};
+
+fn assert_not_converged<P: Projection>(proj: &P) {
+    let err = proj
+        .inverse_project(30_000_000.0, 30_000_000.0)
+        .unwrap_err();
+    assert!(std::matches!(
+        err,
+        ProjectionError::InverseNotConverged { .. }
+    ));
+
+    // Points on the edge of the map are still valid
+    let (x, y) = proj.project(-180.0, 60.0).unwrap();
+    assert!(proj.inverse_project(x, y).is_ok());
+}
+
+#[test]
+fn test_not_converged() {
+    // Inverse projections of these projections are numerical,
+    // and points outside of the map must be reported as not converged
+    assert_not_converged(&WinkelTripel::builder().initialize_projection().unwrap());
+    assert_not_converged(&Aitoff::builder().initialize_projection().unwrap());
+    assert_not_converged(&Hammer::builder().initialize_projection().unwrap());
+    assert_not_converged(&VanDerGrinten::builder().initialize_projection().unwrap());
+}
+
+#[test]
+fn test_constructor() {
+    let err = WinkelTripel::builder()
+        .standard_parallel(95.0)
+        .initialize_projection()
+        .unwrap_err();
+    assert!(std::matches!(err, ProjectionError::ParamOutOfRange { .. }));
+
+    assert!(
+        WinkelTripel::builder()
+            .standard_parallel(40.0)
+            .initialize_projection()
+            .is_ok()
+    );
+}